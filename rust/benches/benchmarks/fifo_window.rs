@@ -30,9 +30,9 @@ where
     Window: FifoWindow<Int, BinOp>,
     BinOp: Operator,
 {
-    let initial_size = (2 as u64).pow(size_exp);
+    let initial_size = 2_u64.pow(size_exp);
     let initial_window = &mut Window::new();
-    let batch_size = (2 as u64).pow(12);
+    let batch_size = 2_u64.pow(12);
     let group = group.throughput(Throughput::Elements(batch_size));
     // Prefill the window
     synthesize(initial_size).for_each(|item| initial_window.push(item));
@@ -0,0 +1,41 @@
+#![no_main]
+use alga::general::Additive;
+use libfuzzer_sys::fuzz_target;
+use std::collections::VecDeque;
+use swag::adaptive::Adaptive;
+use swag::flatfit::FlatFIT;
+use swag::reactive::Reactive;
+use swag::recalc::ReCalc;
+use swag::soe::SoE;
+use swag::two_stacks::TwoStacks;
+use swag::FifoWindow;
+
+/// Interprets every even byte as a push of its value and every odd byte as a pop,
+/// and checks after each step that the window agrees with a `VecDeque` oracle.
+fn run<Window>(data: &[u8])
+where
+    Window: FifoWindow<i64, Additive>,
+{
+    let mut window = Window::new();
+    let mut oracle = VecDeque::new();
+    for byte in data {
+        if byte % 2 == 0 {
+            window.push(*byte as i64);
+            oracle.push_back(*byte as i64);
+        } else {
+            window.pop();
+            oracle.pop_front();
+        }
+        assert_eq!(window.len(), oracle.len());
+        assert_eq!(window.query(), oracle.iter().sum::<i64>());
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    run::<ReCalc<_, _>>(data);
+    run::<SoE<_, _>>(data);
+    run::<Reactive<_, _>>(data);
+    run::<TwoStacks<_, _>>(data);
+    run::<FlatFIT<_, _>>(data);
+    run::<Adaptive<_, _>>(data);
+});
@@ -0,0 +1,169 @@
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
+use crate::recalc::ReCalc;
+use crate::two_stacks::TwoStacks;
+use crate::FifoWindow;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
+use alga::general::Operator;
+
+const DEFAULT_THRESHOLD: usize = 32;
+
+#[derive(Clone)]
+pub struct Adaptive<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    inner: Inner<Value, BinOp>,
+    threshold: usize,
+}
+
+#[derive(Clone)]
+enum Inner<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    ReCalc(ReCalc<Value, BinOp>),
+    TwoStacks(TwoStacks<Value, BinOp>),
+}
+
+impl<Value, BinOp> Adaptive<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Returns the time complexity of the operations of the window, which is that of
+    /// Two-Stacks, since ReCalc and migrations only handle up to `threshold` elements.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::exact(Order::Constant),
+            pop: Bounds::amortized(Order::Constant, Order::Linear),
+            query: Bounds::exact(Order::Constant),
+        }
+    }
+    /// Returns an Adaptive Aggregator which migrates from ReCalc to TwoStacks when
+    /// it holds more than `threshold` elements, and back when it holds fewer than
+    /// half of `threshold` elements.
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            inner: Inner::ReCalc(ReCalc::new()),
+            threshold,
+        }
+    }
+    /// Returns true if the window currently uses ReCalc.
+    pub fn is_recalc(&self) -> bool {
+        matches!(self.inner, Inner::ReCalc(_))
+    }
+    /// Returns an iterator over the values in fifo order.
+    fn values(&self) -> Box<dyn Iterator<Item = &Value> + '_> {
+        match &self.inner {
+            Inner::ReCalc(window) => Box::new(window.values()),
+            Inner::TwoStacks(window) => Box::new(window.values()),
+        }
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The values
+    /// are copied into the segment tree of the snapshot, which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Checks the invariants of the current algorithm, and that ReCalc holds at most
+    /// `threshold` elements and Two-Stacks at least half of `threshold` elements.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        match &self.inner {
+            Inner::ReCalc(window) if window.len() > self.threshold => {
+                Err(InvariantError("ReCalc holds more than threshold elements"))
+            }
+            Inner::TwoStacks(window) if window.len() < self.threshold / 2 => Err(InvariantError(
+                "TwoStacks holds fewer than half of threshold elements",
+            )),
+            Inner::ReCalc(window) => window.check_invariants(),
+            Inner::TwoStacks(window) => window.check_invariants(),
+        }
+    }
+    fn migrate<From, To>(from: &mut From) -> To
+    where
+        From: FifoWindow<Value, BinOp>,
+        To: FifoWindow<Value, BinOp>,
+    {
+        let mut to = To::new();
+        while let Some(v) = from.pop() {
+            to.push(v);
+        }
+        to
+    }
+}
+
+impl<Value, BinOp> FifoWindow<Value, BinOp> for Adaptive<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self::with_threshold(DEFAULT_THRESHOLD)
+    }
+    fn push(&mut self, v: Value) {
+        match &mut self.inner {
+            Inner::ReCalc(window) => {
+                window.push(v);
+                if window.len() > self.threshold {
+                    self.inner = Inner::TwoStacks(Self::migrate(window));
+                }
+            }
+            Inner::TwoStacks(window) => window.push(v),
+        }
+    }
+    fn pop(&mut self) -> Option<Value> {
+        match &mut self.inner {
+            Inner::ReCalc(window) => window.pop(),
+            Inner::TwoStacks(window) => {
+                let v = window.pop();
+                if window.len() < self.threshold / 2 {
+                    self.inner = Inner::ReCalc(Self::migrate(window));
+                }
+                v
+            }
+        }
+    }
+    fn query(&self) -> Value {
+        match &self.inner {
+            Inner::ReCalc(window) => window.query(),
+            Inner::TwoStacks(window) => window.query(),
+        }
+    }
+    fn len(&self) -> usize {
+        match &self.inner {
+            Inner::ReCalc(window) => window.len(),
+            Inner::TwoStacks(window) => window.len(),
+        }
+    }
+    fn is_empty(&self) -> bool {
+        match &self.inner {
+            Inner::ReCalc(window) => window.is_empty(),
+            Inner::TwoStacks(window) => window.is_empty(),
+        }
+    }
+}
+
+impl_eq_by_values! {
+    Adaptive<Value, BinOp>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "allocator_api")]
+pub use std::alloc::Allocator;
+#[cfg(feature = "allocator_api")]
+pub use std::alloc::Global;
+
+/// A stand-in for the unstable `std::alloc::Allocator` trait. With the `allocator_api`
+/// feature (which requires a nightly compiler), heap-backed windows store their values
+/// with a custom allocator. Without it, their allocator can only be `Global`.
+#[cfg(not(feature = "allocator_api"))]
+pub trait Allocator {}
+
+/// A stand-in for the unstable `std::alloc::Global` allocator.
+#[cfg(not(feature = "allocator_api"))]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Global;
+
+#[cfg(not(feature = "allocator_api"))]
+impl Allocator for Global {}
+
+/// Expands to the type of a `Vec` or `VecDeque` of `$val` in the allocator `$alloc`.
+#[cfg(feature = "allocator_api")]
+macro_rules! alloc_type {
+    ($coll:ident<$val:ty, $alloc:ty>) => { $coll<$val, $alloc> };
+}
+
+#[cfg(not(feature = "allocator_api"))]
+macro_rules! alloc_type {
+    ($coll:ident<$val:ty, $alloc:ty>) => { $coll<$val> };
+}
+
+/// Expands to an empty `Vec` or `VecDeque` in the allocator `$alloc`.
+#[cfg(feature = "allocator_api")]
+macro_rules! alloc_new {
+    ($coll:ident, $alloc:expr) => {
+        $coll::new_in($alloc)
+    };
+}
+
+#[cfg(not(feature = "allocator_api"))]
+macro_rules! alloc_new {
+    ($coll:ident, $alloc:expr) => {{
+        let _ = $alloc;
+        $coll::new()
+    }};
+}
@@ -0,0 +1,147 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// The identity of the next window which is created.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A window adaptor which can take in-memory snapshots of its state and roll back to them.
+///
+/// Every window has a unique identity and a version which is incremented by every
+/// mutation. A clone gets a fresh identity, but remembers the identity and version of
+/// every window it descends from, so that it can restore snapshots from before it was
+/// cloned.
+///
+/// The state of the window is shared with its snapshots, and is only copied when the
+/// window is mutated while a snapshot of its current state is still alive.
+pub struct Checkpoint<Window> {
+    window: Rc<Window>,
+    id: u64,
+    version: u64,
+    /// The identity and version of each window this window was cloned from, oldest first
+    ancestors: Rc<[(u64, u64)]>,
+}
+
+/// A snapshot of a window's state. Snapshots are shared, so cloning them is cheap.
+#[derive(Clone)]
+pub struct Snapshot<Window> {
+    window: Rc<Window>,
+    id: u64,
+    version: u64,
+    ancestors: Rc<[(u64, u64)]>,
+}
+
+/// An error which is returned when restoring a snapshot which was not taken from the
+/// history of the window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The snapshot was taken from a different window.
+    Foreign,
+    /// The snapshot was taken from a window which shares an ancestor with the window,
+    /// but after the two had diverged.
+    Diverged,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnapshotError::Foreign => write!(f, "snapshot was taken from a different window"),
+            SnapshotError::Diverged => write!(f, "snapshot was taken from a diverged window"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl<Window> Clone for Checkpoint<Window> {
+    fn clone(&self) -> Self {
+        let ancestors = self.ancestors.iter().copied();
+        Self {
+            window: self.window.clone(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            version: self.version,
+            ancestors: ancestors.chain(Some((self.id, self.version))).collect(),
+        }
+    }
+}
+
+impl<Window> Checkpoint<Window>
+where
+    Window: Clone,
+{
+    /// Captures the current state of the window without copying it.
+    pub fn snapshot(&self) -> Snapshot<Window> {
+        Snapshot {
+            window: self.window.clone(),
+            id: self.id,
+            version: self.version,
+            ancestors: self.ancestors.clone(),
+        }
+    }
+    /// Reinstates the state of the window at the time of `snapshot`, or returns an error
+    /// if the snapshot is not part of the history of the window, i.e., it was taken from
+    /// an unrelated window, or from a related window after they diverged. The state is
+    /// shared with the snapshot rather than copied.
+    pub fn restore(&mut self, snapshot: Snapshot<Window>) -> Result<(), SnapshotError> {
+        let inherited = self
+            .ancestors
+            .iter()
+            .any(|(id, version)| *id == snapshot.id && snapshot.version <= *version);
+        if snapshot.id != self.id && !inherited {
+            let root = |ancestors: &[(u64, u64)], id| ancestors.first().map_or(id, |a| a.0);
+            if root(&snapshot.ancestors, snapshot.id) == root(&self.ancestors, self.id) {
+                return Err(SnapshotError::Diverged);
+            }
+            return Err(SnapshotError::Foreign);
+        }
+        self.window = snapshot.window;
+        self.version += 1;
+        Ok(())
+    }
+    /// Returns the number of mutations of the window so far, including restores.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl<Window> Snapshot<Window> {
+    /// Returns the version of the window at the time of the snapshot.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Checkpoint<Window>
+where
+    Window: FifoWindow<Value, BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Rc::new(Window::new()),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            version: 0,
+            ancestors: Rc::new([]),
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.version += 1;
+        Rc::make_mut(&mut self.window).push(v)
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.version += 1;
+        Rc::make_mut(&mut self.window).pop()
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
@@ -0,0 +1,40 @@
+/// A value which can be split into columns, e.g., a tuple whose fields are stored in
+/// one `Vec` each, which allows exporting a window as a structure of arrays.
+pub trait Columnar: Clone {
+    /// The columns of a sequence of values.
+    type Columns: Default;
+    /// Appends the fields of this value to the back of `columns`.
+    fn push_to(&self, columns: &mut Self::Columns);
+}
+
+/// Implements `Columnar` for tuples of `Clone`able fields.
+macro_rules! impl_columnar {
+    { $(($($field:ident $idx:tt),*)),* } => {
+        $(
+            impl<$($field: Clone),*> Columnar for ($($field,)*) {
+                type Columns = ($(Vec<$field>,)*);
+                fn push_to(&self, columns: &mut Self::Columns) {
+                    $(columns.$idx.push(self.$idx.clone());)*
+                }
+            }
+        )*
+    }
+}
+
+impl_columnar! {
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3)
+}
+
+/// Splits `values` into columns, in order.
+pub(crate) fn collect<'a, Value>(values: impl Iterator<Item = &'a Value>) -> Value::Columns
+where
+    Value: Columnar + 'a,
+{
+    let mut columns = Value::Columns::default();
+    for v in values {
+        v.push_to(&mut columns);
+    }
+    columns
+}
@@ -0,0 +1,42 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+
+/// An operation of a workload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op<Value> {
+    /// Inserts a value at the back of the window.
+    Push(Value),
+    /// Removes a value at the front of the window.
+    Pop,
+}
+
+/// Drives two windows `A` and `B` through the same `workload`, and returns the index
+/// of the first operation after which their queries, popped values, or lengths
+/// diverge, or `None` if they agree at every step.
+pub fn run_parallel<A, B, Value, BinOp>(workload: &[Op<Value>]) -> Option<usize>
+where
+    A: FifoWindow<Value, BinOp>,
+    B: FifoWindow<Value, BinOp>,
+    Value: Clone + PartialEq,
+    BinOp: Operator,
+{
+    let mut a = A::new();
+    let mut b = B::new();
+    for (i, op) in workload.iter().enumerate() {
+        match op {
+            Op::Push(v) => {
+                a.push(v.clone());
+                b.push(v.clone());
+            }
+            Op::Pop => {
+                if a.pop() != b.pop() {
+                    return Some(i);
+                }
+            }
+        }
+        if a.len() != b.len() || a.query() != b.query() {
+            return Some(i);
+        }
+    }
+    None
+}
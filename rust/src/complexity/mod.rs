@@ -0,0 +1,40 @@
+/// The asymptotic cost of an operation in the number of elements `n` of the window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Order {
+    /// `O(1)`
+    Constant,
+    /// `O(log n)`
+    Logarithmic,
+    /// `O(n)`
+    Linear,
+}
+
+/// The amortized and worst-case cost of an operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub amortized: Order,
+    pub worst: Order,
+}
+
+impl Bounds {
+    /// Returns bounds whose amortized and worst-case cost are both `order`.
+    pub const fn exact(order: Order) -> Self {
+        Bounds {
+            amortized: order,
+            worst: order,
+        }
+    }
+    /// Returns bounds with an `amortized` cost and a `worst`-case cost.
+    pub const fn amortized(amortized: Order, worst: Order) -> Self {
+        Bounds { amortized, worst }
+    }
+}
+
+/// The time complexity of the operations of an algorithm, as returned by its
+/// `complexity` function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Complexity {
+    pub push: Bounds,
+    pub pop: Bounds,
+    pub query: Bounds,
+}
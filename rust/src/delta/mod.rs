@@ -0,0 +1,58 @@
+use crate::FifoWindow;
+use crate::Group;
+use crate::Monoid;
+use alga::general::Operator;
+
+/// A window adaptor which remembers the aggregate of its last `query_delta`,
+/// so that it can report how much the aggregate has changed since then.
+#[derive(Clone)]
+pub struct Delta<Window, Value> {
+    window: Window,
+    last: Value,
+}
+
+impl<Window, Value> Delta<Window, Value> {
+    /// Returns the difference between the current aggregate and the aggregate at the
+    /// previous call (or at creation), i.e., `query() - last`. This requires the
+    /// values to be invertible.
+    pub fn query_delta<BinOp>(&mut self) -> Value
+    where
+        Window: FifoWindow<Value, BinOp>,
+        Value: Group<BinOp>,
+        BinOp: Operator,
+    {
+        let agg = self.window.query();
+        let delta = agg.operate(&self.last.two_sided_inverse());
+        self.last = agg;
+        delta
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Delta<Window, Value>
+where
+    Window: FifoWindow<Value, BinOp>,
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            last: Value::identity(),
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.window.push(v)
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.window.pop()
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
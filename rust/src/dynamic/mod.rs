@@ -0,0 +1,81 @@
+use std::rc::Rc;
+
+/// An associative binary operator supplied at runtime.
+type Combine<Value> = Rc<dyn Fn(&Value, &Value) -> Value>;
+
+#[derive(Clone)]
+struct Item<Value>
+where
+    Value: Clone,
+{
+    agg: Value,
+    val: Value,
+}
+
+/// A Two-Stacks window whose binary operator is supplied at runtime as a closure,
+/// which trades some speed for not having to define the operator as a type.
+#[derive(Clone)]
+pub struct DynWindow<Value>
+where
+    Value: Clone,
+{
+    front: Vec<Item<Value>>,
+    back: Vec<Item<Value>>,
+    identity: Value,
+    combine: Combine<Value>,
+}
+
+impl<Value> DynWindow<Value>
+where
+    Value: Clone,
+{
+    /// Returns an empty window which aggregates values with `combine`.
+    /// The `combine` function must be associative and have `identity` as its identity.
+    pub fn new(identity: Value, combine: impl Fn(&Value, &Value) -> Value + 'static) -> Self {
+        Self {
+            front: Vec::new(),
+            back: Vec::new(),
+            identity,
+            combine: Rc::new(combine),
+        }
+    }
+    /// Inserts a value at the back of the window.
+    pub fn push(&mut self, v: Value) {
+        self.back.push(Item {
+            agg: (self.combine)(self.agg(&self.back), &v),
+            val: v,
+        });
+    }
+    /// Removes a value at the front of the window (if any).
+    pub fn pop(&mut self) -> Option<Value> {
+        if self.front.is_empty() {
+            while let Some(top) = self.back.pop() {
+                self.front.push(Item {
+                    agg: (self.combine)(&top.val, self.agg(&self.front)),
+                    val: top.val,
+                })
+            }
+        }
+        self.front.pop().map(|item| item.val)
+    }
+    /// Combines the values in fifo order and returns the result.
+    pub fn query(&self) -> Value {
+        (self.combine)(self.agg(&self.front), self.agg(&self.back))
+    }
+    /// Returns the number of elements inside the window.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+    /// Returns true if the window contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+    #[inline(always)]
+    fn agg<'a>(&'a self, stack: &'a [Item<Value>]) -> &'a Value {
+        if let Some(top) = stack.last() {
+            &top.agg
+        } else {
+            &self.identity
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::DecayedWindow;
+
+/// An exponential moving average, where each push decays the weights of the previous
+/// values by `1 - alpha`, i.e., `ema = alpha*v + (1-alpha)*ema`.
+///
+/// The weight of a value never becomes exactly zero, so values cannot be evicted.
+/// This is why the window implements `DecayedWindow` rather than `FifoWindow`.
+#[derive(Clone, Debug)]
+pub struct EmaWindow {
+    alpha: f64,
+    ema: Option<f64>,
+    len: usize,
+}
+
+impl EmaWindow {
+    /// Returns an empty window with the decay factor `alpha`, where `0 < alpha <= 1`.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0 && alpha <= 1.0, "alpha must be in (0, 1]");
+        Self {
+            alpha,
+            ema: None,
+            len: 0,
+        }
+    }
+    /// Returns the decay factor.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+impl DecayedWindow<f64> for EmaWindow {
+    /// Inserts a value into the window. The first value initializes the average.
+    fn push(&mut self, v: f64) {
+        self.ema = Some(match self.ema {
+            Some(ema) => self.alpha * v + (1.0 - self.alpha) * ema,
+            None => v,
+        });
+        self.len += 1;
+    }
+    fn query(&self) -> Option<f64> {
+        self.ema
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,84 @@
+use crate::FifoWindow;
+use crate::Monoid;
+use alga::general::Operator;
+use std::collections::VecDeque;
+
+/// A window adaptor which replaces values failing a predicate `p` with the identity,
+/// so they still occupy a slot in the window but do not affect its aggregate.
+///
+/// An adaptor which is created by `FifoWindow::new` has no predicate, and aggregates
+/// all values.
+#[derive(Clone)]
+pub struct Filter<Window, Value, P> {
+    window: Window,
+    /// The values which failed the predicate, in fifo order, so they can be returned
+    /// when they are popped.
+    rejected: VecDeque<Option<Value>>,
+    p: Option<P>,
+}
+
+impl<Window, Value, P> Filter<Window, Value, P> {
+    /// Returns an adaptor which only aggregates values of `window` satisfying `p`.
+    /// Values which are already inside `window` are aggregated regardless of `p`.
+    pub fn new<BinOp>(window: Window, p: P) -> Self
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let rejected = std::iter::repeat_with(|| None).take(window.len()).collect();
+        Self {
+            window,
+            rejected,
+            p: Some(p),
+        }
+    }
+}
+
+impl<Window, Value, P, BinOp> FifoWindow<Value, BinOp> for Filter<Window, Value, P>
+where
+    Window: FifoWindow<Value, BinOp>,
+    Value: Monoid<BinOp>,
+    P: Fn(&Value) -> bool + Clone,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            rejected: VecDeque::new(),
+            p: None,
+        }
+    }
+    /// Inserts a value, or the identity if it fails the predicate, at the back of the window.
+    fn push(&mut self, v: Value) {
+        match &self.p {
+            Some(p) if !p(&v) => {
+                self.window.push(Value::identity());
+                self.rejected.push_back(Some(v));
+            }
+            _ => {
+                self.window.push(v);
+                self.rejected.push_back(None);
+            }
+        }
+    }
+    /// Removes a value at the front of the window (if any), including values which
+    /// failed the predicate.
+    fn pop(&mut self) -> Option<Value> {
+        let v = self.window.pop()?;
+        match self.rejected.pop_front() {
+            Some(Some(rejected)) => Some(rejected),
+            _ => Some(v),
+        }
+    }
+    /// Combines the values satisfying the predicate in fifo order and returns the result.
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    /// Returns the number of elements inside the window, including filtered ones.
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::rc::Rc;
+
+/// A window adaptor which holds at most `capacity` elements by automatically
+/// popping the oldest element when pushing into a full window.
+///
+/// An adaptor which is created by `FifoWindow::new` has an unbounded capacity.
+#[derive(Clone)]
+pub struct Fixed<Window, Value> {
+    window: Window,
+    capacity: usize,
+    on_evict: Option<Rc<dyn Fn(Value)>>,
+}
+
+impl<Window, Value> Fixed<Window, Value> {
+    /// Returns an adaptor which bounds `window` to `capacity` elements.
+    /// Elements which exceed the capacity are evicted immediately.
+    pub fn new<BinOp>(window: Window, capacity: usize) -> Self
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let mut new = Self {
+            window,
+            capacity,
+            on_evict: None,
+        };
+        new.evict::<BinOp>();
+        new
+    }
+    /// Registers a callback which is invoked with every automatically evicted value,
+    /// in fifo order. Without a callback, evicted values are dropped.
+    pub fn on_evict(mut self, f: impl Fn(Value) + 'static) -> Self {
+        self.on_evict = Some(Rc::new(f));
+        self
+    }
+    /// Returns the maximum number of elements inside the window.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Changes the maximum number of elements inside the window. Shrinking the window
+    /// immediately evicts its oldest elements, which are passed to the callback if one
+    /// is registered. Returns the number of evicted elements.
+    pub fn set_capacity<BinOp>(&mut self, capacity: usize) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let len = self.window.len();
+        self.capacity = capacity;
+        self.evict::<BinOp>();
+        len - self.window.len()
+    }
+    /// Pops elements until the window is within its capacity, and passes them to the
+    /// callback if one is registered.
+    fn evict<BinOp>(&mut self)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        while self.window.len() > self.capacity {
+            if let Some(v) = self.window.pop() {
+                if let Some(on_evict) = &self.on_evict {
+                    on_evict(v);
+                }
+            }
+        }
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Fixed<Window, Value>
+where
+    Window: FifoWindow<Value, BinOp>,
+    Value: Clone,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            capacity: usize::MAX,
+            on_evict: None,
+        }
+    }
+    /// Inserts a value at the back of the window, and evicts the value at the front
+    /// of the window if the window was full.
+    fn push(&mut self, v: Value) {
+        self.window.push(v);
+        self.evict::<BinOp>();
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.window.pop()
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
@@ -1,5 +1,12 @@
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
 use crate::FifoWindow;
-use alga::general::AbstractMonoid;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
 use alga::general::Operator;
 use std::cell::RefCell;
 use std::marker::PhantomData;
@@ -9,12 +16,14 @@ const LOW_CAP: usize = 2;
 #[derive(Clone)]
 pub struct FlatFIT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     front: usize,
     back: usize,
     size: usize,
+    /// The values of the window, at the same positions as their items in the buffer
+    values: Vec<Value>,
     buffer: RefCell<Vec<Item<Value>>>,
     tracing_indices: RefCell<Vec<usize>>,
     binop: PhantomData<BinOp>,
@@ -22,19 +31,20 @@ where
 
 #[derive(Clone)]
 struct Item<Value> {
-    val: Value,
+    /// The aggregate of the values from this item up to (but excluding) the `next` item
+    agg: Value,
     next: usize,
 }
 
 impl<Value> Item<Value> {
-    fn new(val: Value, next: usize) -> Self {
-        Self { val, next }
+    fn new(agg: Value, next: usize) -> Self {
+        Self { agg, next }
     }
 }
 
 impl<Value, BinOp> FifoWindow<Value, BinOp> for FlatFIT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     fn new() -> Self {
@@ -42,6 +52,7 @@ where
             front: 0,
             back: 0,
             size: 0,
+            values: Vec::new(),
             buffer: RefCell::new(Vec::new()),
             tracing_indices: RefCell::new(Vec::new()),
             binop: PhantomData,
@@ -53,26 +64,41 @@ where
             self.rescale(capacity * 2)
         }
         let mut buffer = self.buffer.borrow_mut();
-        self.size += 1;
         let prev = self.back;
-        self.back = (self.back + 1) % self.size;
-        buffer[self.back].val = val;
+        if self.size > 0 {
+            self.back = (self.back + 1) % buffer.len();
+        } else {
+            self.back = self.front;
+        }
+        self.size += 1;
+        buffer[self.back].agg = val.clone();
         buffer[prev].next = self.back;
+        self.values[self.back] = val;
     }
     fn pop(&mut self) -> Option<Value> {
         if self.size > 0 {
-            let item = self.buffer.borrow().get(self.front).map(|item| item.val.clone());
+            let val = std::mem::replace(&mut self.values[self.front], Value::identity());
             let capacity = self.buffer.borrow().capacity();
             self.front = (self.front + 1) % capacity;
             self.size -= 1;
             if self.size < capacity / 2 {
                 self.rescale(capacity / 2)
             }
-            item
+            Some(val)
         } else {
             None
         }
     }
+    /// Pops before pushing, so that a full buffer is not grown and shrunk again.
+    fn slide(&mut self, v: Value) -> Value {
+        match self.pop() {
+            Some(val) => {
+                self.push(v);
+                val
+            }
+            None => v,
+        }
+    }
     fn query(&self) -> Value {
         let mut agg = Value::identity();
         let mut buffer = self.buffer.borrow_mut();
@@ -86,10 +112,10 @@ where
             }
             // FlatFIT queries mutate the internal buffer
             while let Some(i) = tracing_indices.pop() {
-                agg = agg.operate(&buffer[i].val);
+                agg = buffer[i].agg.operate(&agg);
                 buffer[i] = Item::new(agg.clone(), self.back);
             }
-            agg = agg.operate(&buffer[self.back].val);
+            agg = agg.operate(&buffer[self.back].agg);
         }
         agg
     }
@@ -103,25 +129,76 @@ where
 
 impl<Value, BinOp> FlatFIT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
+    /// Returns the time complexity of the operations of the window. Queries compress
+    /// the pointers that they traverse, and the buffer is rescaled when it becomes full
+    /// or sparse.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::amortized(Order::Constant, Order::Linear),
+            pop: Bounds::amortized(Order::Constant, Order::Linear),
+            query: Bounds::amortized(Order::Constant, Order::Linear),
+        }
+    }
+    /// Returns an iterator over the values in fifo order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        let capacity = self.values.len();
+        (0..self.size).map(move |i| &self.values[(self.front + i) % capacity])
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The values,
+    /// rather than the partial aggregates of the buffer, are copied into a segment tree,
+    /// which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Checks that the window fits within the buffer, and that following the pointers
+    /// from the front of the window only moves towards the back of the window.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        let buffer = self.buffer.borrow();
+        let capacity = buffer.len();
+        if self.size == 0 {
+            return Ok(());
+        }
+        if self.size > capacity || (self.front + self.size - 1) % capacity != self.back {
+            return Err(InvariantError("window does not fit within the buffer"));
+        }
+        // Offset of the element at `i` from the front of the window
+        let offset = |i: usize| (i + capacity - self.front) % capacity;
+        for i in 0..self.size - 1 {
+            let current = (self.front + i) % capacity;
+            let next = buffer[current].next;
+            if next >= capacity || offset(next) <= i || offset(next) >= self.size {
+                return Err(InvariantError("pointer does not move towards the back"));
+            }
+        }
+        Ok(())
+    }
     fn rescale(&mut self, new_capacity: usize) {
         let new_capacity = std::cmp::max(new_capacity, LOW_CAP);
-        let mut new_buffer: Vec<Item<Value>> = Vec::with_capacity(new_capacity);
-        unsafe {
-            // Unsafe is used here so we don't need to initialize the buffer's contents
-            new_buffer.set_len(new_capacity);
-        }
+        let mut new_buffer: Vec<Item<Value>> = vec![Item::new(Value::identity(), 0); new_capacity];
+        let mut new_values = vec![Value::identity(); new_capacity];
         let mut buffer = self.buffer.borrow_mut();
         let old_capacity = buffer.len();
         for i in 0..self.size {
-            let item = &buffer[(self.front + i) % old_capacity];
+            let j = (self.front + i) % old_capacity;
+            let item = &buffer[j];
             let offset = (item.next + old_capacity - self.front) % old_capacity;
-            new_buffer[i].val = item.val.clone();
+            new_buffer[i].agg = item.agg.clone();
             new_buffer[i].next = offset;
+            new_values[i] = std::mem::replace(&mut self.values[j], Value::identity());
         }
         *buffer = new_buffer;
+        self.values = new_values;
         self.front = 0;
         if self.size == 0 {
             self.back = 0;
@@ -130,3 +207,10 @@ where
         }
     }
 }
+
+impl_eq_by_values! {
+    FlatFIT<Value, BinOp>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+}
@@ -0,0 +1,82 @@
+use crate::segtree::SegTree;
+use crate::FifoWindow;
+use crate::Monoid;
+use crate::SubWindow;
+use alga::general::Operator;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// An immutable snapshot of a window, which can be queried but not modified.
+///
+/// The values are kept in a segment tree behind an `Arc`, so cloning the snapshot
+/// takes `O(1)` and every sub-range can be aggregated in `O(log n)`.
+pub struct FrozenWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    tree: Arc<SegTree<Value, BinOp>>,
+}
+
+impl<Value, BinOp> Clone for FrozenWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<Value, BinOp> FrozenWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Returns a snapshot of `values` in fifo order, which copies them in O(n) time.
+    pub(crate) fn new<'a>(values: impl Iterator<Item = &'a Value>) -> Self
+    where
+        Value: 'a,
+    {
+        let mut tree = SegTree::new();
+        for v in values {
+            tree.push(v.clone());
+        }
+        Self::from_tree(tree)
+    }
+    /// Returns a snapshot of the values of `tree`.
+    pub(crate) fn from_tree(tree: SegTree<Value, BinOp>) -> Self {
+        Self {
+            tree: Arc::new(tree),
+        }
+    }
+    /// Combines the values in fifo order and returns the result.
+    pub fn query(&self) -> Value {
+        self.tree.query()
+    }
+    /// Returns an iterator over the values in fifo order.
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        self.tree.values()
+    }
+    /// Returns the number of elements inside the snapshot.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+    /// Returns true if the snapshot contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+}
+
+impl<Value, BinOp> SubWindow<usize, Value> for FrozenWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Aggregates the values at positions `range`, counted from the front of the snapshot.
+    fn range_query(&self, range: Range<usize>) -> Value {
+        self.tree.range_query(range)
+    }
+}
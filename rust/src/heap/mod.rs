@@ -0,0 +1,133 @@
+use crate::ops::Selection;
+use crate::FifoWindow;
+use crate::Monoid;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A window for selection operators like `Max` and `Min`, which keeps the sequence
+/// numbers of its values in a binary heap. Popped values are deleted lazily from the heap
+/// once they reach its top, and the heap is rebuilt once popped values make up more than
+/// half of it, so that its size stays proportional to the window.
+#[derive(Clone)]
+pub struct HeapWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp> + Ord,
+    BinOp: Selection,
+{
+    /// Values in fifo order, including popped values which may still be in the heap
+    values: VecDeque<Value>,
+    /// Sequence numbers of the values, ordered so that the selected value is on top
+    heap: Vec<usize>,
+    /// Sequence number of the first value in `values`
+    base: usize,
+    /// Sequence number of the value at the front of the window
+    front: usize,
+    op: PhantomData<BinOp>,
+}
+
+impl<Value, BinOp> FifoWindow<Value, BinOp> for HeapWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp> + Ord,
+    BinOp: Selection,
+{
+    fn new() -> Self {
+        Self {
+            values: VecDeque::new(),
+            heap: Vec::new(),
+            base: 0,
+            front: 0,
+            op: PhantomData,
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.heap.push(self.base + self.values.len());
+        self.values.push_back(v);
+        self.sift_up(self.heap.len() - 1);
+    }
+    fn pop(&mut self) -> Option<Value> {
+        if self.is_empty() {
+            return None;
+        }
+        let v = self.values[self.front - self.base].clone();
+        self.front += 1;
+        while let Some(seq) = self.heap.first() {
+            if *seq < self.front {
+                self.heap.swap_remove(0);
+                self.sift_down(0);
+            } else {
+                break;
+            }
+        }
+        // Every value in the heap is also in `values`, so this bounds both
+        if self.values.len() > 2 * self.len() {
+            self.rebuild();
+        }
+        Some(v)
+    }
+    fn query(&self) -> Value {
+        match self.heap.first() {
+            Some(seq) => self.value(*seq).clone(),
+            None => Value::identity(),
+        }
+    }
+    fn len(&self) -> usize {
+        self.base + self.values.len() - self.front
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<Value, BinOp> HeapWindow<Value, BinOp>
+where
+    Value: Monoid<BinOp> + Ord,
+    BinOp: Selection,
+{
+    /// Returns the number of values in the heap, including popped values
+    /// which have not been deleted yet.
+    pub fn heap_len(&self) -> usize {
+        self.heap.len()
+    }
+    fn value(&self, seq: usize) -> &Value {
+        &self.values[seq - self.base]
+    }
+    /// Returns true if the value at heap index `i` is selected over the one at `j`.
+    fn selects(&self, i: usize, j: usize) -> bool {
+        BinOp::cmp(self.value(self.heap[i]), self.value(self.heap[j])) == Ordering::Greater
+    }
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.selects(i, parent) {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut top = i;
+            for child in [2 * i + 1, 2 * i + 2].iter() {
+                if *child < self.heap.len() && self.selects(*child, top) {
+                    top = *child;
+                }
+            }
+            if top == i {
+                break;
+            }
+            self.heap.swap(i, top);
+            i = top;
+        }
+    }
+    /// Drops the popped values and rebuilds the heap from the remaining ones.
+    fn rebuild(&mut self) {
+        self.values.drain(..self.front - self.base);
+        self.base = self.front;
+        self.heap = (self.front..self.front + self.values.len()).collect();
+        for i in (0..self.heap.len() / 2).rev() {
+            self.sift_down(i);
+        }
+    }
+}
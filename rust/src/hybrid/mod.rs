@@ -0,0 +1,100 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::collections::VecDeque;
+use std::ops::Sub;
+
+/// A window adaptor which evicts elements both by count and by age, i.e., it holds at
+/// most `max_count` elements which are no older than `max_age`. Elements are inserted
+/// in time order, and whichever bound is exceeded first triggers the eviction.
+#[derive(Clone)]
+pub struct HybridWindow<Window, Time> {
+    window: Window,
+    times: VecDeque<Time>,
+    max_count: Option<usize>,
+    max_age: Option<Time>,
+}
+
+impl<Window, Time> HybridWindow<Window, Time>
+where
+    Time: Ord + Copy + Sub<Output = Time>,
+{
+    /// Returns an adaptor around the empty `window` which is unbounded until
+    /// `max_count` or `max_age` is set.
+    pub fn new<Value, BinOp>(window: Window) -> Self
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        assert!(window.is_empty(), "Window must be empty");
+        Self {
+            window,
+            times: VecDeque::new(),
+            max_count: None,
+            max_age: None,
+        }
+    }
+    /// Bounds the window to at most `max_count` elements.
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+    /// Bounds the window to elements which are at most `max_age` older than the latest time.
+    pub fn max_age(mut self, max_age: Time) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+    /// Inserts the tuple `(t,v)` at the back of the window, advances the window to time `t`,
+    /// and returns the number of evicted elements. Times must be non-decreasing.
+    pub fn insert<Value, BinOp>(&mut self, t: Time, v: Value) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        if let Some(last) = self.times.back() {
+            assert!(*last <= t, "Times must be non-decreasing");
+        }
+        self.window.push(v);
+        self.times.push_back(t);
+        self.advance(t)
+    }
+    /// Evicts the elements which exceed one of the bounds at time `now`,
+    /// and returns the number of evicted elements.
+    pub fn advance<Value, BinOp>(&mut self, now: Time) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let mut evicted = 0;
+        while let Some(t) = self.times.front() {
+            let too_many = self.max_count.is_some_and(|n| self.times.len() > n);
+            let too_old = self.max_age.is_some_and(|age| *t < now && now - *t > age);
+            if !too_many && !too_old {
+                break;
+            }
+            self.times.pop_front();
+            self.window.pop();
+            evicted += 1;
+        }
+        evicted
+    }
+    /// Combines the values in fifo order and returns the result.
+    pub fn query<Value, BinOp>(&self) -> Value
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.query()
+    }
+    /// Returns the number of elements inside the window.
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+    /// Returns true if the window contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+    /// Returns the time of the oldest element inside the window (if any).
+    pub fn oldest(&self) -> Option<Time> {
+        self.times.front().copied()
+    }
+}
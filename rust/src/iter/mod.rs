@@ -0,0 +1,70 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::marker::PhantomData;
+
+/// An iterator adaptor which pushes every item of an iterator into a window of at most
+/// `size` items, and yields the aggregate of the window after every `stride` pushes.
+#[derive(Clone)]
+pub struct SlidingAggregate<I, Window, BinOp> {
+    iter: I,
+    window: Window,
+    size: usize,
+    stride: usize,
+    op: PhantomData<BinOp>,
+}
+
+impl<I, Window, BinOp> SlidingAggregate<I, Window, BinOp> {
+    /// Yields the aggregate only once per `stride` pushes instead of after every push,
+    /// e.g., to downsample the output. The first aggregate is yielded after `stride`
+    /// pushes, and pushes after the last full stride are not reported.
+    ///
+    /// Panics if `stride` is zero.
+    pub fn stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "stride must be positive");
+        self.stride = stride;
+        self
+    }
+}
+
+impl<I, Window, BinOp> Iterator for SlidingAggregate<I, Window, BinOp>
+where
+    I: Iterator,
+    Window: FifoWindow<I::Item, BinOp>,
+    BinOp: Operator,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        for _ in 0..self.stride {
+            self.window.push(self.iter.next()?);
+            while self.window.len() > self.size {
+                self.window.pop();
+            }
+        }
+        Some(self.window.query())
+    }
+}
+
+/// An extension trait for aggregating iterators over a sliding window.
+pub trait SlidingAggregateExt: Iterator + Sized {
+    /// Returns an iterator over the aggregates of the last (up to) `size` items of
+    /// this iterator, which are maintained by `window`.
+    fn sliding_aggregate<Window, BinOp>(
+        self,
+        window: Window,
+        size: usize,
+    ) -> SlidingAggregate<Self, Window, BinOp>
+    where
+        Window: FifoWindow<Self::Item, BinOp>,
+        BinOp: Operator,
+    {
+        SlidingAggregate {
+            iter: self,
+            window,
+            size,
+            stride: 1,
+            op: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> SlidingAggregateExt for I {}
@@ -0,0 +1,83 @@
+use crate::Monoid;
+use alga::general::Operator;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A windowed join of a left and a right stream, which holds the `capacity` most recent
+/// elements of each stream, keyed by `K`. Inserting an element matches it against the
+/// elements of the other stream with an equal key, and aggregates the matched pairs,
+/// each of which is lifted into a value by a function `f` and combined under `BinOp`.
+///
+/// The aggregate depends on the inserted element through `f`, so it cannot be
+/// maintained incrementally. Every insert scans the other stream, which takes
+/// O(`capacity`) time.
+#[derive(Clone)]
+pub struct JoinWindow<K, L, R, F, BinOp> {
+    left: VecDeque<(K, L)>,
+    right: VecDeque<(K, R)>,
+    capacity: usize,
+    f: F,
+    op: PhantomData<BinOp>,
+}
+
+impl<K, L, R, F, Value, BinOp> JoinWindow<K, L, R, F, BinOp>
+where
+    K: PartialEq,
+    F: Fn(&L, &R) -> Value,
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Returns an empty join which holds up to `capacity` elements of each stream,
+    /// and which lifts matched pairs into values with `f`.
+    pub fn new(capacity: usize, f: F) -> Self {
+        Self {
+            left: VecDeque::new(),
+            right: VecDeque::new(),
+            capacity,
+            f,
+            op: PhantomData,
+        }
+    }
+    /// Inserts an element into the left stream, evicting its oldest element if the
+    /// stream is full, and returns the aggregate of the pairs which it matched, in
+    /// the order of the right stream. Takes O(`capacity`) time.
+    pub fn insert_left(&mut self, k: K, l: L) -> Value {
+        let agg = self
+            .right
+            .iter()
+            .filter(|(key, _)| *key == k)
+            .fold(Value::identity(), |acc, (_, r)| {
+                acc.operate(&(self.f)(&l, r))
+            });
+        Self::insert(&mut self.left, self.capacity, k, l);
+        agg
+    }
+    /// Inserts an element into the right stream, evicting its oldest element if the
+    /// stream is full, and returns the aggregate of the pairs which it matched, in
+    /// the order of the left stream. Takes O(`capacity`) time.
+    pub fn insert_right(&mut self, k: K, r: R) -> Value {
+        let agg = self
+            .left
+            .iter()
+            .filter(|(key, _)| *key == k)
+            .fold(Value::identity(), |acc, (_, l)| {
+                acc.operate(&(self.f)(l, &r))
+            });
+        Self::insert(&mut self.right, self.capacity, k, r);
+        agg
+    }
+    fn insert<T>(stream: &mut VecDeque<(K, T)>, capacity: usize, k: K, v: T) {
+        stream.push_back((k, v));
+        if stream.len() > capacity {
+            stream.pop_front();
+        }
+    }
+    /// Returns the number of elements of the left and right stream inside the window.
+    pub fn len(&self) -> (usize, usize) {
+        (self.left.len(), self.right.len())
+    }
+    /// Returns true if neither stream has elements inside the window.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+}
@@ -0,0 +1,99 @@
+use crate::FifoWindow;
+use crate::Monoid;
+use alga::general::Operator;
+use rand::Rng;
+use std::fmt::Debug;
+
+/// The number of previously pushed values which are sampled as operands.
+const SAMPLES: usize = 16;
+
+/// A window adaptor which checks that the operation is a monoid on every push, by
+/// verifying the identity laws on the pushed value and associativity on the pushed
+/// value and two randomly sampled values which were pushed before it.
+///
+/// Tree-combining algorithms silently return wrong results for operations which
+/// violate these laws, so the adaptor instead panics and names the offending values.
+#[derive(Clone)]
+pub struct Lawful<Window, Value> {
+    window: Window,
+    samples: Vec<Value>,
+}
+
+impl<Window, Value> Lawful<Window, Value> {
+    /// Panics if `a·b·c` depends on how it is parenthesized.
+    fn check_associativity<BinOp>(a: &Value, b: &Value, c: &Value)
+    where
+        Value: Monoid<BinOp> + PartialEq + Debug,
+        BinOp: Operator,
+    {
+        let left = a.operate(b).operate(c);
+        let right = a.operate(&b.operate(c));
+        assert!(
+            left == right,
+            "operation is not associative: (a·b)·c = {:?} but a·(b·c) = {:?} for a = {:?}, b = {:?}, c = {:?}",
+            left,
+            right,
+            a,
+            b,
+            c
+        );
+    }
+    /// Panics if the identity is not neutral on both sides of `v`.
+    fn check_identity<BinOp>(v: &Value)
+    where
+        Value: Monoid<BinOp> + PartialEq + Debug,
+        BinOp: Operator,
+    {
+        let e = Value::identity();
+        let left = e.operate(v);
+        let right = v.operate(&e);
+        assert!(
+            left == *v && right == *v,
+            "identity is not neutral: e·v = {:?} and v·e = {:?} for v = {:?}",
+            left,
+            right,
+            v
+        );
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Lawful<Window, Value>
+where
+    Window: FifoWindow<Value, BinOp>,
+    Value: Monoid<BinOp> + PartialEq + Debug,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            samples: Vec::with_capacity(SAMPLES),
+        }
+    }
+    fn push(&mut self, v: Value) {
+        let mut rng = rand::thread_rng();
+        Self::check_identity(&v);
+        if !self.samples.is_empty() {
+            let a = &self.samples[rng.gen_range(0, self.samples.len())];
+            let b = &self.samples[rng.gen_range(0, self.samples.len())];
+            Self::check_associativity(a, b, &v);
+        }
+        if self.samples.len() < SAMPLES {
+            self.samples.push(v.clone());
+        } else {
+            self.samples[rng.gen_range(0, SAMPLES)] = v.clone();
+        }
+        self.window.push(v)
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.window.pop()
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
@@ -1,6 +1,229 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(
+    feature = "simd",
+    feature(portable_simd, min_specialization, cfg_overflow_checks)
+)]
+
+use alga::general::AbstractGroup;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
 use alga::general::Operator;
+use std::fmt;
 use std::ops::Range;
 
+/// A value which can be combined associatively under the binary operator `BinOp`,
+/// but which may lack an identity. Use `ops::Maybe` to aggregate it in a window.
+///
+/// This is implemented for every `Clone`able alga semigroup.
+pub trait Semigroup<BinOp>: AbstractSemigroup<BinOp> + Clone
+where
+    BinOp: Operator,
+{
+}
+
+impl<Value, BinOp> Semigroup<BinOp> for Value
+where
+    Value: AbstractSemigroup<BinOp> + Clone,
+    BinOp: Operator,
+{
+}
+
+/// A value which can be aggregated by a window under the binary operator `BinOp`.
+///
+/// This is implemented for every `Clone`able alga monoid, so custom value types
+/// only need to implement `AbstractMonoid<BinOp>` (associativity and identity).
+pub trait Monoid<BinOp>: Semigroup<BinOp> + AbstractMonoid<BinOp>
+where
+    BinOp: Operator,
+{
+}
+
+impl<Value, BinOp> Monoid<BinOp> for Value
+where
+    Value: AbstractMonoid<BinOp> + Clone,
+    BinOp: Operator,
+{
+}
+
+/// A monoid value whose binary operator is commutative, so that values can be combined
+/// in any order, e.g., when merging the aggregates of windows which overlap in time.
+///
+/// This is implemented for every monoid whose operator implements `ops::Commutative`.
+pub trait CommutativeMonoid<BinOp>: Monoid<BinOp>
+where
+    BinOp: ops::Commutative,
+{
+}
+
+impl<Value, BinOp> CommutativeMonoid<BinOp> for Value
+where
+    Value: Monoid<BinOp>,
+    BinOp: ops::Commutative,
+{
+}
+
+/// A monoid value which can also be inverted, e.g., to subtract it on eviction.
+///
+/// This is implemented for every `Clone`able alga group.
+pub trait Group<BinOp>: Monoid<BinOp> + AbstractGroup<BinOp>
+where
+    BinOp: Operator,
+{
+}
+
+impl<Value, BinOp> Group<BinOp> for Value
+where
+    Value: AbstractGroup<BinOp> + Clone,
+    BinOp: Operator,
+{
+}
+
+/// A partial aggregate which is turned into a distinct output type after querying.
+///
+/// Aggregation happens in three steps: input values are lifted into partial
+/// aggregates with `From`, partials are combined with `operate`, and the final
+/// partial aggregate of a query is lowered into the output exactly once.
+pub trait Lower<BinOp>: Monoid<BinOp>
+where
+    BinOp: Operator,
+{
+    /// The type of the output.
+    type Output;
+    /// Returns the output of a partial aggregate.
+    fn lower(&self) -> Self::Output;
+}
+
+/// A partial aggregate which can be lowered into an existing output, e.g., to reuse
+/// the allocation of a buffer across queries.
+pub trait LowerInto<BinOp>: Lower<BinOp>
+where
+    BinOp: Operator,
+{
+    /// Overwrites `buf` with the output of a partial aggregate.
+    fn lower_into(&self, buf: &mut Self::Output);
+}
+
+/// An error which is returned by a query when the aggregate is not a valid result.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QueryError {
+    /// The aggregate overflowed its representation.
+    Overflow,
+    /// The aggregate of an empty window is undefined.
+    Empty,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryError::Overflow => write!(f, "aggregate overflowed"),
+            QueryError::Empty => write!(f, "aggregate of an empty window"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// An internal invariant of a window which does not hold, as reported by the
+/// `check_invariants` method of its algorithm.
+#[cfg(feature = "inspect")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InvariantError(pub &'static str);
+
+#[cfg(feature = "inspect")]
+impl fmt::Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invariant violated: {}", self.0)
+    }
+}
+
+#[cfg(feature = "inspect")]
+impl std::error::Error for InvariantError {}
+
+/// A value whose aggregate may be invalid, e.g., because it overflowed.
+///
+/// Infallible values derive this with the `infallible!` macro, as the default
+/// check always succeeds.
+pub trait Fallible<BinOp>: Monoid<BinOp>
+where
+    BinOp: Operator,
+{
+    /// Returns an error if the aggregate is not a valid result.
+    fn check(&self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+/// Implements `Fallible` with the default check, which always succeeds, for values
+/// which cannot fail under `BinOp`. Generic values take their generic parameters
+/// in braces before the type, e.g., `infallible!(Sum => {T: Ord + Clone} Mode<T>)`.
+#[macro_export]
+macro_rules! infallible {
+    { $BinOp:ty => $($({$($gen:tt)*})? $Value:ty),+ $(,)? } => {
+        $(impl<$($($gen)*)?> $crate::Fallible<$BinOp> for $Value {})+
+    };
+}
+
+/// Implements `PartialEq`, `Eq` and `Hash` for a window in terms of its `values`,
+/// which iterates over the values of the window in fifo order. The bounds of the
+/// window follow it after `where`.
+macro_rules! impl_eq_by_values {
+    { $Window:ident<$($param:ident),+> where $($bound:tt)+ } => {
+        impl<$($param),+> PartialEq for $Window<$($param),+>
+        where
+            Value: PartialEq,
+            $($bound)+
+        {
+            /// Windows are equal if they contain equal values in the same order.
+            fn eq(&self, other: &Self) -> bool {
+                self.values().eq(other.values())
+            }
+        }
+
+        impl<$($param),+> Eq for $Window<$($param),+>
+        where
+            Value: Eq,
+            $($bound)+
+        {
+        }
+
+        impl<$($param),+> std::hash::Hash for $Window<$($param),+>
+        where
+            Value: std::hash::Hash,
+            $($bound)+
+        {
+            /// Hashes the values in fifo order, so equal windows have equal hashes.
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                state.write_usize(self.values().count());
+                self.values().for_each(|v| v.hash(state));
+            }
+        }
+    };
+}
+
+/// A value which may be absorbing, i.e., equal to the result of combining it with any
+/// other value, e.g., `true` under logical or. Once an aggregate is absorbing, combining
+/// it with more values is redundant.
+///
+/// ReCalc windows which are built with `ReCalcBuilder::absorbing` stop combining values
+/// once their aggregate is absorbing. The default is never absorbing.
+pub trait Absorbing<BinOp>: Monoid<BinOp>
+where
+    BinOp: Operator,
+{
+    /// Returns true if combining `self` with any other value returns `self`.
+    fn is_absorbing(&self) -> bool {
+        false
+    }
+}
+
+/// A sink which receives the aggregates of windows, e.g., to roll up the aggregates of
+/// per-minute windows into an hourly total.
+pub trait Accumulator<Value> {
+    /// Adds the aggregate of a window to the accumulator.
+    fn accumulate(&mut self, agg: Value);
+}
+
 /// An abstract data type which maintains a time-ordered sliding window.
 pub trait TimeWindow<Time, Value, BinOp>: Clone
 where
@@ -34,6 +257,93 @@ where
     fn len(&self) -> usize;
     /// Returns true if the window contains no elements.
     fn is_empty(&self) -> bool;
+    /// Inserts a value at the back of the window and removes the value at the front, so
+    /// that the number of elements stays constant, and returns the removed value. If the
+    /// window is empty, `v` itself is removed.
+    fn slide(&mut self, v: Value) -> Value {
+        self.push(v);
+        self.pop().expect("window contains the pushed value")
+    }
+    /// Inserts a value at the back of the window and returns the new aggregate, which
+    /// is the same as a `push` followed by a `query`.
+    fn push_query(&mut self, v: Value) -> Value {
+        self.push(v);
+        self.query()
+    }
+    /// Inserts clones of the values of `slice` at the back of the window, in order.
+    fn push_all(&mut self, slice: &[Value])
+    where
+        Value: Clone,
+    {
+        for v in slice {
+            self.push(v.clone());
+        }
+    }
+    /// Feeds the aggregate of the window into the accumulator `acc`.
+    fn fold_into<Acc>(&self, acc: &mut Acc)
+    where
+        Acc: Accumulator<Value>,
+    {
+        acc.accumulate(self.query())
+    }
+    /// Returns the result of `query` as if `v` had been pushed, without modifying
+    /// the window.
+    fn peek_query(&self, v: &Value) -> Value
+    where
+        Value: AbstractMagma<BinOp>,
+    {
+        self.query().operate(v)
+    }
+    /// Returns the aggregate of this window minus the aggregate of the `other` window,
+    /// e.g., the difference between the sums of two streams.
+    fn diff(&self, other: &Self) -> Value
+    where
+        Value: Group<BinOp>,
+    {
+        self.query().operate(&other.query().two_sided_inverse())
+    }
+    /// Combines the values in fifo order and returns the result, or an error if the
+    /// result is invalid.
+    fn try_query(&self) -> Result<Value, QueryError>
+    where
+        Value: Fallible<BinOp>,
+    {
+        let agg = self.query();
+        agg.check()?;
+        Ok(agg)
+    }
+    /// Combines the values in fifo order and lowers the result into its output.
+    fn query_lowered(&self) -> Value::Output
+    where
+        Value: Lower<BinOp>,
+    {
+        self.query().lower()
+    }
+    /// Combines the values in fifo order and lowers the result into `buf`, whose
+    /// allocation is reused. Only the output is spared an allocation: the aggregate is
+    /// computed by `query` as usual, which may allocate for values such as `LastN`.
+    fn query_into(&self, buf: &mut Value::Output)
+    where
+        Value: LowerInto<BinOp>,
+    {
+        self.query().lower_into(buf)
+    }
+}
+
+/// An abstract data type which maintains an aggregate where the weights of older values
+/// decay, e.g., an exponential moving average. The weight of a value never becomes
+/// exactly zero, so values cannot be evicted and the window has no `pop`.
+pub trait DecayedWindow<Value>: Clone {
+    /// Inserts a value at the back of the window, and decays the previous values.
+    fn push(&mut self, v: Value);
+    /// Returns the decayed aggregate, or `None` if no value has been pushed.
+    fn query(&self) -> Option<Value>;
+    /// Returns the number of values pushed into the window.
+    fn len(&self) -> usize;
+    /// Returns true if no value has been pushed into the window.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// An abstract data type which maintains sliding sub-window aggregates.
@@ -41,10 +351,38 @@ pub trait SubWindow<Time, Value>
 where
     Time: Ord,
 {
-    /// Returns the aggregate of a subwindow.
+    /// Returns the aggregate of a subwindow. The range is clamped to the window, so
+    /// empty and reversed ranges aggregate to the identity.
     fn range_query(&self, range: Range<Time>) -> Value;
+    /// Returns the aggregates of a batch of subwindows, in the same order as `ranges`.
+    fn query_ranges(&self, ranges: &[Range<Time>]) -> Vec<Value>
+    where
+        Time: Clone,
+    {
+        ranges
+            .iter()
+            .map(|range| self.range_query(range.clone()))
+            .collect()
+    }
 }
 
+/// Combines the aggregate of the `older` window with the aggregate of the `newer`
+/// window, as if the values of both had been pushed into a single window in that order.
+/// The windows may use different algorithms.
+pub fn merge_windows<Older, Newer, Value, BinOp>(older: &Older, newer: &Newer) -> Value
+where
+    Older: FifoWindow<Value, BinOp>,
+    Newer: FifoWindow<Value, BinOp>,
+    Value: AbstractMagma<BinOp>,
+    BinOp: Operator,
+{
+    older.query().operate(&newer.query())
+}
+
+/// Allocator Support
+#[macro_use]
+pub mod alloc;
+
 /// Recalculate-From-Scratch
 pub mod recalc;
 
@@ -59,3 +397,95 @@ pub mod reactive;
 
 /// Flat and Fast Index Traverser
 pub mod flatfit;
+
+/// Segment Tree
+pub mod segtree;
+
+/// Retractable Window
+pub mod retract;
+
+/// Binary Operators and Values
+pub mod ops;
+
+/// Adaptive-Aggregator
+pub mod adaptive;
+
+/// Fixed-Size-Adaptor
+pub mod fixed;
+
+/// Tumbling-Sliding-Adaptor
+pub mod tumbling;
+
+/// Hybrid Count-and-Time-Adaptor
+pub mod hybrid;
+
+/// Lazy-Deletion Heap
+pub mod heap;
+
+/// Map-Adaptor
+pub mod map;
+
+/// Output-Map-Adaptor
+pub mod output;
+
+/// Operation Builder
+pub mod operation;
+
+/// Span-Adaptor
+pub mod span;
+
+/// Checkpoint-Adaptor
+pub mod checkpoint;
+
+/// Frozen Snapshot
+pub mod frozen;
+
+/// Two-Heaps Median Window
+pub mod median;
+
+/// Metering-Adaptor
+pub mod metered;
+
+/// Delta-Adaptor
+pub mod delta;
+
+/// Dynamic-Operator Window
+pub mod dynamic;
+
+/// Exponential Moving Average
+pub mod ema;
+
+/// Filter-Adaptor
+pub mod filter;
+
+/// Weighted-Eviction-Adaptor
+pub mod weighted;
+
+/// Windowed Stream Join
+pub mod join;
+
+/// Single-Producer Single-Consumer Adaptor
+pub mod spsc;
+
+/// Iterator-Adaptor
+pub mod iter;
+
+/// Columnar Export
+pub mod columns;
+
+/// Cross-Validation of Algorithms
+pub mod compare;
+
+/// Algorithm Recommendation
+pub mod recommend;
+
+/// Time Complexity of Algorithms
+pub mod complexity;
+
+/// Testing Utilities
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Law-Checking Adaptor
+#[cfg(feature = "laws")]
+pub mod laws;
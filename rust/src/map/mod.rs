@@ -0,0 +1,52 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+
+/// A window adaptor which applies a function `f` to every value before it is
+/// pushed, e.g., mapping `|x| x * x` under `Sum` aggregates the sum of squares.
+///
+/// An adaptor which is created by `FifoWindow::new` has no function, and pushes
+/// values unchanged.
+#[derive(Clone)]
+pub struct Map<Window, F> {
+    window: Window,
+    f: Option<F>,
+}
+
+impl<Window, F> Map<Window, F> {
+    /// Returns an adaptor which maps values through `f` before pushing them into `window`.
+    pub fn new(window: Window, f: F) -> Self {
+        Self { window, f: Some(f) }
+    }
+}
+
+impl<Window, F, Value, BinOp> FifoWindow<Value, BinOp> for Map<Window, F>
+where
+    Window: FifoWindow<Value, BinOp>,
+    F: Fn(Value) -> Value + Clone,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            f: None,
+        }
+    }
+    fn push(&mut self, v: Value) {
+        match &self.f {
+            Some(f) => self.window.push(f(v)),
+            None => self.window.push(v),
+        }
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.window.pop()
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
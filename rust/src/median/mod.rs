@@ -0,0 +1,148 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+
+/// Which heap holds a value of the window.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Half {
+    Lower,
+    Upper,
+}
+
+/// A window which maintains the median of its values with two heaps: a max-heap of the
+/// lower half and a min-heap of the upper half. The median is not a monoid, which is why
+/// this is a window of its own instead of a value for the general algorithms.
+///
+/// Evicted values are deleted lazily from the heaps once they reach the top, and the
+/// heaps are compacted once evicted values make up more than half of them.
+#[derive(Clone)]
+pub struct MedianWindow<T: Ord + Clone> {
+    lower: BinaryHeap<(T, usize)>,
+    upper: BinaryHeap<Reverse<(T, usize)>>,
+    /// The values in fifo order, and the heaps which hold them
+    fifo: VecDeque<(T, Half)>,
+    /// Sequence number of the value at the front of the window
+    front: usize,
+    /// Number of non-evicted values in each heap
+    lower_len: usize,
+    upper_len: usize,
+}
+
+impl<T: Ord + Clone> Default for MedianWindow<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> MedianWindow<T> {
+    /// Returns an empty window.
+    pub fn new() -> Self {
+        Self {
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+            fifo: VecDeque::new(),
+            front: 0,
+            lower_len: 0,
+            upper_len: 0,
+        }
+    }
+    /// Inserts a value at the back of the window.
+    pub fn push(&mut self, v: T) {
+        let seq = self.front + self.fifo.len();
+        let half = match self.lower.peek() {
+            Some((top, _)) if v > *top => Half::Upper,
+            _ => Half::Lower,
+        };
+        match half {
+            Half::Lower => {
+                self.lower.push((v.clone(), seq));
+                self.lower_len += 1;
+            }
+            Half::Upper => {
+                self.upper.push(Reverse((v.clone(), seq)));
+                self.upper_len += 1;
+            }
+        }
+        self.fifo.push_back((v, half));
+        self.rebalance();
+    }
+    /// Removes a value at the front of the window (if any).
+    pub fn pop(&mut self) -> Option<T> {
+        let (v, half) = self.fifo.pop_front()?;
+        self.front += 1;
+        match half {
+            Half::Lower => self.lower_len -= 1,
+            Half::Upper => self.upper_len -= 1,
+        }
+        self.prune();
+        self.rebalance();
+        if self.heap_len() > 2 * self.len() {
+            self.compact();
+        }
+        Some(v)
+    }
+    /// Returns the lower median of the window, i.e., the value at position `(n-1)/2`
+    /// in sorted order, or `None` if the window is empty.
+    pub fn query(&self) -> Option<T> {
+        self.lower.peek().map(|(v, _)| v.clone())
+    }
+    /// Returns the lower and upper median of the window, which are equal if the
+    /// window contains an odd number of values.
+    pub fn medians(&self) -> Option<(T, T)> {
+        let lower = self.query()?;
+        if self.lower_len > self.upper_len {
+            Some((lower.clone(), lower))
+        } else {
+            let Reverse((upper, _)) = self.upper.peek()?;
+            Some((lower, upper.clone()))
+        }
+    }
+    /// Returns the number of elements inside the window.
+    pub fn len(&self) -> usize {
+        self.fifo.len()
+    }
+    /// Returns true if the window contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.fifo.is_empty()
+    }
+    /// Returns the number of values in the heaps, including evicted values
+    /// which have not been deleted yet.
+    pub fn heap_len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+    /// Moves values between the heaps until the lower half has as many values as
+    /// the upper half, or one more.
+    fn rebalance(&mut self) {
+        while self.lower_len > self.upper_len + 1 {
+            let (v, seq) = self.lower.pop().unwrap();
+            self.fifo[seq - self.front].1 = Half::Upper;
+            self.upper.push(Reverse((v, seq)));
+            self.lower_len -= 1;
+            self.upper_len += 1;
+            self.prune();
+        }
+        while self.upper_len > self.lower_len {
+            let Reverse((v, seq)) = self.upper.pop().unwrap();
+            self.fifo[seq - self.front].1 = Half::Lower;
+            self.lower.push((v, seq));
+            self.upper_len -= 1;
+            self.lower_len += 1;
+            self.prune();
+        }
+    }
+    /// Deletes the evicted values at the tops of the heaps.
+    fn prune(&mut self) {
+        while matches!(self.lower.peek(), Some((_, seq)) if *seq < self.front) {
+            self.lower.pop();
+        }
+        while matches!(self.upper.peek(), Some(Reverse((_, seq))) if *seq < self.front) {
+            self.upper.pop();
+        }
+    }
+    /// Deletes all evicted values from the heaps.
+    fn compact(&mut self) {
+        let front = self.front;
+        self.lower.retain(|(_, seq)| *seq >= front);
+        self.upper.retain(|Reverse((_, seq))| *seq >= front);
+    }
+}
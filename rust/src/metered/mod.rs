@@ -0,0 +1,102 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::cell::Cell;
+use std::time::Instant;
+
+/// The latencies of one kind of window operation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Latency {
+    /// The number of calls.
+    pub count: u64,
+    /// The total time spent in all calls.
+    pub total_nanos: u64,
+    /// The duration of the fastest call, or zero if there were no calls.
+    pub min_nanos: u64,
+    /// The duration of the slowest call, or zero if there were no calls.
+    pub max_nanos: u64,
+}
+
+impl Latency {
+    /// Returns the mean duration of a call, or zero if there were no calls.
+    pub fn mean_nanos(&self) -> u64 {
+        self.total_nanos.checked_div(self.count).unwrap_or(0)
+    }
+    fn record(&mut self, nanos: u64) {
+        if self.count == 0 || nanos < self.min_nanos {
+            self.min_nanos = nanos;
+        }
+        self.max_nanos = self.max_nanos.max(nanos);
+        self.total_nanos += nanos;
+        self.count += 1;
+    }
+}
+
+/// The latencies of all operations on a metered window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub push: Latency,
+    pub pop: Latency,
+    pub query: Latency,
+}
+
+/// A window adaptor which measures the number and duration of the pushes,
+/// pops, and queries of the window it wraps.
+#[derive(Clone)]
+pub struct Metered<Window> {
+    window: Window,
+    stats: Cell<Stats>,
+}
+
+impl<Window> Metered<Window> {
+    /// Returns the latencies of the operations so far.
+    pub fn stats(&self) -> Stats {
+        self.stats.get()
+    }
+    /// Forgets the latencies of the operations so far.
+    pub fn reset(&mut self) {
+        self.stats.set(Stats::default());
+    }
+    /// Records the time elapsed since `start` in the latency selected by `latency`.
+    fn record(&self, latency: impl Fn(&mut Stats) -> &mut Latency, start: Instant) {
+        let nanos = start.elapsed().as_nanos() as u64;
+        let mut stats = self.stats.get();
+        latency(&mut stats).record(nanos);
+        self.stats.set(stats);
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Metered<Window>
+where
+    Window: FifoWindow<Value, BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            stats: Cell::new(Stats::default()),
+        }
+    }
+    fn push(&mut self, v: Value) {
+        let start = Instant::now();
+        self.window.push(v);
+        self.record(|stats| &mut stats.push, start);
+    }
+    fn pop(&mut self) -> Option<Value> {
+        let start = Instant::now();
+        let v = self.window.pop();
+        self.record(|stats| &mut stats.pop, start);
+        v
+    }
+    fn query(&self) -> Value {
+        let start = Instant::now();
+        let v = self.window.query();
+        self.record(|stats| &mut stats.query, start);
+        v
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::map::Map;
+use crate::ops;
+use crate::Monoid;
+use alga::general::Operator;
+use std::marker::PhantomData;
+
+/// A pipeline of functions which lifts inputs of type `In` into optional values,
+/// where `None` means that the input was filtered out.
+#[derive(Clone)]
+pub struct OperationBuilder<In, F> {
+    f: F,
+    input: PhantomData<fn(In)>,
+}
+
+/// An `OperationBuilder` whose values are aggregated under the binary operator `BinOp`.
+#[derive(Clone)]
+pub struct Operation<In, F, BinOp> {
+    f: F,
+    op: PhantomData<(fn(In), BinOp)>,
+}
+
+/// Returns an empty pipeline, which passes inputs through unchanged, e.g.,
+/// `op().filter(|x: &i64| x % 2 == 0).map(|x| x * x).sum()` is an operation for the
+/// sum of squares of even inputs.
+pub fn op<In>() -> OperationBuilder<In, impl Fn(In) -> Option<In> + Clone> {
+    OperationBuilder::from_fn(Some)
+}
+
+impl<In, F> OperationBuilder<In, F> {
+    fn from_fn(f: F) -> Self {
+        Self {
+            f,
+            input: PhantomData,
+        }
+    }
+    /// Drops values which fail the predicate `p`.
+    pub fn filter<T, P>(self, p: P) -> OperationBuilder<In, impl Fn(In) -> Option<T> + Clone>
+    where
+        F: Fn(In) -> Option<T> + Clone,
+        P: Fn(&T) -> bool + Clone,
+    {
+        let f = self.f;
+        OperationBuilder::from_fn(move |input| f(input).filter(|v| p(v)))
+    }
+    /// Applies the function `g` to every value.
+    pub fn map<T, U, G>(self, g: G) -> OperationBuilder<In, impl Fn(In) -> Option<U> + Clone>
+    where
+        F: Fn(In) -> Option<T> + Clone,
+        G: Fn(T) -> U + Clone,
+    {
+        let f = self.f;
+        OperationBuilder::from_fn(move |input| f(input).map(&g))
+    }
+    /// Aggregates the values under the binary operator `BinOp`.
+    pub fn fold<BinOp>(self) -> Operation<In, F, BinOp>
+    where
+        BinOp: Operator,
+    {
+        Operation {
+            f: self.f,
+            op: PhantomData,
+        }
+    }
+    /// Aggregates the values under `ops::Sum`.
+    pub fn sum(self) -> Operation<In, F, ops::Sum> {
+        self.fold()
+    }
+    /// Aggregates the values under `ops::Product`.
+    pub fn product(self) -> Operation<In, F, ops::Product> {
+        self.fold()
+    }
+    /// Aggregates the values under `ops::Max`.
+    pub fn max(self) -> Operation<In, F, ops::Max> {
+        self.fold()
+    }
+    /// Aggregates the values under `ops::Min`.
+    pub fn min(self) -> Operation<In, F, ops::Min> {
+        self.fold()
+    }
+}
+
+impl<In, F, BinOp> Operation<In, F, BinOp>
+where
+    BinOp: Operator,
+{
+    /// Lifts an input into a value, or into the identity if it was filtered out.
+    pub fn lift<Value>(&self, input: In) -> Value
+    where
+        F: Fn(In) -> Option<Value>,
+        Value: Monoid<BinOp>,
+    {
+        (self.f)(input).unwrap_or_else(Value::identity)
+    }
+    /// Returns an adaptor which lifts inputs with this operation before pushing them
+    /// into `window`. Filtered inputs still occupy a slot in the window. Operations
+    /// which change the type of their inputs can instead `lift` them into the window.
+    pub fn window<Window>(self, window: Window) -> Map<Window, impl Fn(In) -> In + Clone>
+    where
+        F: Fn(In) -> Option<In> + Clone,
+        In: Monoid<BinOp>,
+    {
+        let f = self.f;
+        Map::new(window, move |input| f(input).unwrap_or_else(In::identity))
+    }
+}
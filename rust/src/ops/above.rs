@@ -0,0 +1,53 @@
+use crate::ops::Min;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the earliest value which exceeds a threshold, together with
+/// its absolute index in the stream, or `None` if no value exceeds the threshold.
+///
+/// Two partials are combined by keeping the value with the smaller index, preferring
+/// the left one on ties.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FirstAbove<T>(pub Option<(usize, T)>);
+
+impl<T: PartialOrd> FirstAbove<T> {
+    /// Lifts the value `v` at absolute `index` into a partial if it exceeds `threshold`,
+    /// and into the identity otherwise.
+    pub fn lift(index: usize, v: T, threshold: &T) -> Self {
+        if v > *threshold {
+            FirstAbove(Some((index, v)))
+        } else {
+            FirstAbove(None)
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Lower<Min> for FirstAbove<T> {
+    type Output = Option<(usize, T)>;
+    fn lower(&self) -> Option<(usize, T)> {
+        self.0.clone()
+    }
+}
+
+impl<T> Identity<Min> for FirstAbove<T> {
+    fn identity() -> FirstAbove<T> {
+        FirstAbove(None)
+    }
+}
+
+impl<T: Clone> AbstractMagma<Min> for FirstAbove<T> {
+    fn operate(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some((i, _)), Some((j, _))) if j < i => other.clone(),
+            (None, _) => other.clone(),
+            _ => self.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> AbstractSemigroup<Min> for FirstAbove<T> {}
+impl<T: Clone + PartialEq> AbstractMonoid<Min> for FirstAbove<T> {}
+infallible!(Min => {T: Clone + PartialEq} FirstAbove<T>);
@@ -0,0 +1,49 @@
+use crate::ops::Max;
+use crate::Absorbing;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A boolean which is true if any value of the window is true, i.e., the maximum of
+/// the values where `false < true`.
+///
+/// `true` is absorbing, so ReCalc can stop combining values once it is reached.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Any(pub bool);
+
+impl From<bool> for Any {
+    fn from(v: bool) -> Self {
+        Any(v)
+    }
+}
+
+impl Lower<Max> for Any {
+    type Output = bool;
+    fn lower(&self) -> bool {
+        self.0
+    }
+}
+
+impl Identity<Max> for Any {
+    fn identity() -> Any {
+        Any(false)
+    }
+}
+
+impl AbstractMagma<Max> for Any {
+    fn operate(&self, other: &Self) -> Self {
+        Any(self.0 || other.0)
+    }
+}
+
+impl AbstractSemigroup<Max> for Any {}
+impl AbstractMonoid<Max> for Any {}
+infallible!(Max => Any);
+
+impl Absorbing<Max> for Any {
+    fn is_absorbing(&self) -> bool {
+        self.0
+    }
+}
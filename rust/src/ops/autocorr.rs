@@ -0,0 +1,106 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the lag-`K` autocorrelation of the window, i.e., the
+/// covariance of the pairs `(x_i, x_(i+K))` divided by the variance, which is lowered
+/// into an `f64`. The autocorrelation of a window with at most `K` values is `NaN`.
+///
+/// Besides the sums of values, squares, and lagged products of the segment, the
+/// partial keeps its first and last `K` values. Two consecutive segments are
+/// concatenated by adding the products of the pairs which straddle the boundary,
+/// i.e., between the last `K` values of the older segment and the first `K` values of
+/// the newer segment.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct AutoCorr<const K: usize> {
+    /// The (up to) `K` first values of the segment.
+    pub head: Vec<f64>,
+    /// The (up to) `K` last values of the segment.
+    pub tail: Vec<f64>,
+    pub count: usize,
+    pub sum: f64,
+    pub sum_sq: f64,
+    /// The sum of products `x_i * x_(i+K)`.
+    pub cross: f64,
+}
+
+impl<const K: usize> From<f64> for AutoCorr<K> {
+    fn from(v: f64) -> Self {
+        let ends = if K == 0 { vec![] } else { vec![v] };
+        AutoCorr {
+            head: ends.clone(),
+            tail: ends,
+            count: 1,
+            sum: v,
+            sum_sq: v * v,
+            cross: if K == 0 { v * v } else { 0.0 },
+        }
+    }
+}
+
+impl<const K: usize> Lower<Concat> for AutoCorr<K> {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        if self.count <= K {
+            return f64::NAN;
+        }
+        let n = self.count as f64;
+        let mean = self.sum / n;
+        // The sums of the first and last `n-K` values, which are paired by the lag.
+        let leading = self.sum - self.tail.iter().sum::<f64>();
+        let trailing = self.sum - self.head.iter().sum::<f64>();
+        let covariance = self.cross - mean * (leading + trailing) + (n - K as f64) * mean * mean;
+        let variance = self.sum_sq - n * mean * mean;
+        covariance / variance
+    }
+}
+
+impl<const K: usize> Identity<Concat> for AutoCorr<K> {
+    fn identity() -> AutoCorr<K> {
+        AutoCorr::default()
+    }
+}
+
+impl<const K: usize> AbstractMagma<Concat> for AutoCorr<K> {
+    fn operate(&self, other: &Self) -> Self {
+        if other.count == 0 {
+            return self.clone();
+        }
+        if self.count == 0 {
+            return other.clone();
+        }
+        let boundary = self
+            .tail
+            .iter()
+            .chain(other.head.iter())
+            .collect::<Vec<_>>();
+        let older = self.tail.len();
+        let cross = (0..older)
+            .filter(|i| i + K >= older && i + K < boundary.len())
+            .map(|i| boundary[i] * boundary[i + K])
+            .sum::<f64>();
+        let tail = self.tail.iter().chain(other.tail.iter());
+        let skip = (self.tail.len() + other.tail.len()).saturating_sub(K);
+        AutoCorr {
+            head: self
+                .head
+                .iter()
+                .chain(other.head.iter())
+                .take(K)
+                .cloned()
+                .collect(),
+            tail: tail.skip(skip).cloned().collect(),
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            sum_sq: self.sum_sq + other.sum_sq,
+            cross: self.cross + other.cross + cross,
+        }
+    }
+}
+
+impl<const K: usize> AbstractSemigroup<Concat> for AutoCorr<K> {}
+impl<const K: usize> AbstractMonoid<Concat> for AutoCorr<K> {}
+infallible!(Concat => {const K: usize} AutoCorr<K>);
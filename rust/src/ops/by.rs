@@ -0,0 +1,105 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use std::marker::PhantomData;
+
+/// A key function which projects values onto an ordered key, e.g., a field of a struct.
+pub trait Key<Value> {
+    /// The type of the key.
+    type Key: Ord;
+    /// Returns the key of `v`.
+    fn key(v: &Value) -> Self::Key;
+}
+
+/// Binary operator for selecting the value with the largest key under the key function
+/// `K`. On ties, the newest value is selected, like `Iterator::max_by_key`.
+/// Has the following properties:
+/// * Associativity
+pub struct MaxBy<K>(PhantomData<K>);
+
+/// Binary operator for selecting the value with the smallest key under the key function
+/// `K`. On ties, the oldest value is selected, like `Iterator::min_by_key`.
+/// Has the following properties:
+/// * Associativity
+pub struct MinBy<K>(PhantomData<K>);
+
+impl<K> Clone for MaxBy<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for MaxBy<K> {}
+
+impl<K> Operator for MaxBy<K> {
+    fn operator_token() -> MaxBy<K> {
+        MaxBy(PhantomData)
+    }
+}
+
+impl<K> Clone for MinBy<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for MinBy<K> {}
+
+impl<K> Operator for MinBy<K> {
+    fn operator_token() -> MinBy<K> {
+        MinBy(PhantomData)
+    }
+}
+
+/// A value which is selected by its key, where `None` represents the aggregate of an
+/// empty window.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct By<Value>(pub Option<Value>);
+
+impl<Value> From<Value> for By<Value> {
+    fn from(v: Value) -> Self {
+        By(Some(v))
+    }
+}
+
+impl<Value, K> Identity<MaxBy<K>> for By<Value> {
+    fn identity() -> By<Value> {
+        By(None)
+    }
+}
+
+impl<Value: Clone, K: Key<Value>> AbstractMagma<MaxBy<K>> for By<Value> {
+    fn operate(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) if K::key(a) > K::key(b) => self.clone(),
+            (_, None) => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+impl<Value: Clone + PartialEq, K: Key<Value>> AbstractSemigroup<MaxBy<K>> for By<Value> {}
+impl<Value: Clone + PartialEq, K: Key<Value>> AbstractMonoid<MaxBy<K>> for By<Value> {}
+infallible!(MaxBy<K> => {Value: Clone + PartialEq, K: Key<Value>} By<Value>);
+
+impl<Value, K> Identity<MinBy<K>> for By<Value> {
+    fn identity() -> By<Value> {
+        By(None)
+    }
+}
+
+impl<Value: Clone, K: Key<Value>> AbstractMagma<MinBy<K>> for By<Value> {
+    fn operate(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) if K::key(b) < K::key(a) => other.clone(),
+            (None, _) => other.clone(),
+            _ => self.clone(),
+        }
+    }
+}
+
+impl<Value: Clone + PartialEq, K: Key<Value>> AbstractSemigroup<MinBy<K>> for By<Value> {}
+impl<Value: Clone + PartialEq, K: Key<Value>> AbstractMonoid<MinBy<K>> for By<Value> {}
+infallible!(MinBy<K> => {Value: Clone + PartialEq, K: Key<Value>} By<Value>);
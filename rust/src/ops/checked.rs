@@ -0,0 +1,66 @@
+use crate::ops::Sum;
+use crate::Fallible;
+use crate::QueryError;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use std::convert::TryFrom;
+
+/// A sum of `i64` values which is invalid if it overflows an `i64`.
+///
+/// Partial sums are widened, so that intermediate overflows (which depend on the
+/// order of combination) do not affect the result, and only the aggregate of the
+/// whole window is checked.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CheckedSum(i128);
+
+impl CheckedSum {
+    /// Returns the sum, or `None` if it overflows an `i64`.
+    pub fn get(&self) -> Option<i64> {
+        i64::try_from(self.0).ok()
+    }
+}
+
+impl From<i64> for CheckedSum {
+    fn from(v: i64) -> Self {
+        CheckedSum(v as i128)
+    }
+}
+
+impl Identity<Sum> for CheckedSum {
+    fn identity() -> CheckedSum {
+        CheckedSum(0)
+    }
+}
+
+impl AbstractMagma<Sum> for CheckedSum {
+    fn operate(&self, other: &Self) -> Self {
+        CheckedSum(self.0 + other.0)
+    }
+}
+
+impl TwoSidedInverse<Sum> for CheckedSum {
+    fn two_sided_inverse(&self) -> CheckedSum {
+        CheckedSum(-self.0)
+    }
+}
+
+impl AbstractSemigroup<Sum> for CheckedSum {}
+impl AbstractMonoid<Sum> for CheckedSum {}
+impl AbstractQuasigroup<Sum> for CheckedSum {}
+impl AbstractLoop<Sum> for CheckedSum {}
+impl AbstractGroup<Sum> for CheckedSum {}
+
+impl Fallible<Sum> for CheckedSum {
+    fn check(&self) -> Result<(), QueryError> {
+        match self.get() {
+            Some(_) => Ok(()),
+            None => Err(QueryError::Overflow),
+        }
+    }
+}
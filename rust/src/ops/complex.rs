@@ -0,0 +1,48 @@
+use crate::ops::Sum;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A complex number with a real and an imaginary `f64` component.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// Returns the complex number `re + im*i`.
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+}
+
+impl Identity<Sum> for Complex {
+    fn identity() -> Complex {
+        Complex::new(0.0, 0.0)
+    }
+}
+
+impl AbstractMagma<Sum> for Complex {
+    fn operate(&self, other: &Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl TwoSidedInverse<Sum> for Complex {
+    fn two_sided_inverse(&self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl AbstractSemigroup<Sum> for Complex {}
+impl AbstractMonoid<Sum> for Complex {}
+infallible!(Sum => Complex);
+impl AbstractQuasigroup<Sum> for Complex {}
+impl AbstractLoop<Sum> for Complex {}
+impl AbstractGroup<Sum> for Complex {}
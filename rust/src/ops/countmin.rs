@@ -0,0 +1,68 @@
+use crate::ops::Sum;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use std::hash::Hash;
+
+/// A Count-Min sketch with `D` rows of `W` counters, which estimates the frequency of
+/// every value of the window in constant space. The estimate of a value never
+/// under-counts it, and over-counts it by the number of other values which collide
+/// with it in every row.
+///
+/// Sketches are summed by adding their counters element-wise. Counters are unsigned
+/// and cannot be subtracted, so the sketch cannot be aggregated under subtract-on-evict.
+/// Instead, evicting a value requires recombining the partials of the remaining values,
+/// like ReCalc does from scratch.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CountMin<const D: usize, const W: usize> {
+    /// The counters in row-major order.
+    pub counters: Vec<u64>,
+}
+
+impl<const D: usize, const W: usize> CountMin<D, W> {
+    /// Returns the index of the counter of `v` in every row.
+    fn indices<T: Hash>(v: &T) -> impl Iterator<Item = usize> + '_ {
+        (0..D).map(move |row| row * W + (fxhash::hash64(&(row, v)) % W as u64) as usize)
+    }
+    /// Lifts `v` into a sketch which counts it once.
+    pub fn lift<T: Hash>(v: &T) -> Self {
+        let mut sketch = Self::identity();
+        for i in Self::indices(v) {
+            sketch.counters[i] += 1;
+        }
+        sketch
+    }
+    /// Returns the estimated number of occurrences of `v`.
+    pub fn estimate<T: Hash>(&self, v: &T) -> u64 {
+        Self::indices(v)
+            .map(|i| self.counters[i])
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+impl<const D: usize, const W: usize> Identity<Sum> for CountMin<D, W> {
+    fn identity() -> CountMin<D, W> {
+        CountMin {
+            counters: vec![0; D * W],
+        }
+    }
+}
+
+impl<const D: usize, const W: usize> AbstractMagma<Sum> for CountMin<D, W> {
+    fn operate(&self, other: &Self) -> Self {
+        CountMin {
+            counters: self
+                .counters
+                .iter()
+                .zip(other.counters.iter())
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+}
+
+impl<const D: usize, const W: usize> AbstractSemigroup<Sum> for CountMin<D, W> {}
+impl<const D: usize, const W: usize> AbstractMonoid<Sum> for CountMin<D, W> {}
+infallible!(Sum => {const D: usize, const W: usize} CountMin<D, W>);
@@ -0,0 +1,84 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the population covariance matrix of `D`-dimensional inputs,
+/// which is the count, the mean of every dimension, and the co-moment matrix (the sum
+/// of products of deviations from the means), and which is lowered into a `D×D` matrix.
+/// The covariance matrix of an empty window is filled with `NaN`.
+///
+/// Partials are summed with the parallel algorithm of Chan et al., like `Variance`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CovarianceMatrix<const D: usize> {
+    pub count: f64,
+    pub mean: [f64; D],
+    pub comoment: [[f64; D]; D],
+}
+
+impl<const D: usize> From<[f64; D]> for CovarianceMatrix<D> {
+    fn from(v: [f64; D]) -> Self {
+        CovarianceMatrix {
+            count: 1.0,
+            mean: v,
+            comoment: [[0.0; D]; D],
+        }
+    }
+}
+
+impl<const D: usize> Lower<Sum> for CovarianceMatrix<D> {
+    type Output = [[f64; D]; D];
+    fn lower(&self) -> [[f64; D]; D] {
+        let mut covariance = self.comoment;
+        for c in covariance.iter_mut().flatten() {
+            *c /= self.count;
+        }
+        covariance
+    }
+}
+
+impl<const D: usize> Identity<Sum> for CovarianceMatrix<D> {
+    fn identity() -> CovarianceMatrix<D> {
+        CovarianceMatrix {
+            count: 0.0,
+            mean: [0.0; D],
+            comoment: [[0.0; D]; D],
+        }
+    }
+}
+
+impl<const D: usize> AbstractMagma<Sum> for CovarianceMatrix<D> {
+    fn operate(&self, other: &Self) -> Self {
+        if other.count == 0.0 {
+            return *self;
+        }
+        if self.count == 0.0 {
+            return *other;
+        }
+        let count = self.count + other.count;
+        let mut delta = [0.0; D];
+        let mut mean = self.mean;
+        for i in 0..D {
+            delta[i] = other.mean[i] - self.mean[i];
+            mean[i] += delta[i] * other.count / count;
+        }
+        let scale = self.count * other.count / count;
+        let mut comoment = self.comoment;
+        for i in 0..D {
+            for j in 0..D {
+                comoment[i][j] += other.comoment[i][j] + delta[i] * delta[j] * scale;
+            }
+        }
+        CovarianceMatrix {
+            count,
+            mean,
+            comoment,
+        }
+    }
+}
+
+impl<const D: usize> AbstractSemigroup<Sum> for CovarianceMatrix<D> {}
+impl<const D: usize> AbstractMonoid<Sum> for CovarianceMatrix<D> {}
+infallible!(Sum => {const D: usize} CovarianceMatrix<D>);
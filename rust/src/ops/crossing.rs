@@ -0,0 +1,63 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the number of times that consecutive values of the window
+/// cross a fixed level, i.e., where one value is below the level and the other is not,
+/// which is lowered into that number.
+///
+/// Values are lifted into the side of the level which they are on. Two consecutive
+/// segments are concatenated by adding their crossings, and one more crossing if the
+/// last value of the older segment and the first value of the newer segment are on
+/// different sides of the level.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct CrossingRate {
+    /// Whether the first and last value of the segment are below the level, or `None`
+    /// if it is empty.
+    pub ends: Option<(bool, bool)>,
+    pub crossings: usize,
+}
+
+impl CrossingRate {
+    /// Lifts `v` into the side of `level` which it is on.
+    pub fn lift<T: PartialOrd>(v: &T, level: &T) -> Self {
+        let below = v < level;
+        CrossingRate {
+            ends: Some((below, below)),
+            crossings: 0,
+        }
+    }
+}
+
+impl Lower<Concat> for CrossingRate {
+    type Output = usize;
+    fn lower(&self) -> usize {
+        self.crossings
+    }
+}
+
+impl Identity<Concat> for CrossingRate {
+    fn identity() -> CrossingRate {
+        CrossingRate::default()
+    }
+}
+
+impl AbstractMagma<Concat> for CrossingRate {
+    fn operate(&self, other: &Self) -> Self {
+        match (self.ends, other.ends) {
+            (Some((first, last)), Some((next, newest))) => CrossingRate {
+                ends: Some((first, newest)),
+                crossings: self.crossings + other.crossings + (last != next) as usize,
+            },
+            (_, None) => *self,
+            (None, _) => *other,
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for CrossingRate {}
+impl AbstractMonoid<Concat> for CrossingRate {}
+infallible!(Concat => CrossingRate);
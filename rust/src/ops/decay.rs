@@ -0,0 +1,88 @@
+use crate::ops::Sum;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of an exponentially decayed count of timestamped events, where
+/// an event at time `t` contributes `exp(-lambda * (now - t))` to the count at time
+/// `now`, which is chosen when querying the count with `at`.
+///
+/// The decay factor of `now` is factored out of the sum, which instead stores
+/// `sum(exp(lambda * (t - anchor)))` relative to the latest timestamp `anchor`, so that
+/// the terms never exceed one. Partials are summed by rescaling them to the later
+/// anchor, and inverted by negating the sum.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DecayedCount {
+    pub lambda: f64,
+    pub anchor: f64,
+    pub scaled: f64,
+}
+
+impl DecayedCount {
+    /// Returns the count of an event at time `t` which decays at the rate `lambda`.
+    pub fn new(t: f64, lambda: f64) -> Self {
+        DecayedCount {
+            lambda,
+            anchor: t,
+            scaled: 1.0,
+        }
+    }
+    /// Returns the decayed count at time `now`.
+    pub fn at(&self, now: f64) -> f64 {
+        if self.scaled == 0.0 {
+            0.0
+        } else {
+            self.scaled * (-self.lambda * (now - self.anchor)).exp()
+        }
+    }
+}
+
+impl Identity<Sum> for DecayedCount {
+    fn identity() -> DecayedCount {
+        DecayedCount {
+            lambda: 0.0,
+            anchor: f64::NEG_INFINITY,
+            scaled: 0.0,
+        }
+    }
+}
+
+impl AbstractMagma<Sum> for DecayedCount {
+    fn operate(&self, other: &Self) -> Self {
+        if other.scaled == 0.0 {
+            return *self;
+        }
+        if self.scaled == 0.0 {
+            return *other;
+        }
+        let lambda = self.lambda.max(other.lambda);
+        let anchor = self.anchor.max(other.anchor);
+        DecayedCount {
+            lambda,
+            anchor,
+            scaled: self.scaled * (lambda * (self.anchor - anchor)).exp()
+                + other.scaled * (lambda * (other.anchor - anchor)).exp(),
+        }
+    }
+}
+
+impl TwoSidedInverse<Sum> for DecayedCount {
+    fn two_sided_inverse(&self) -> DecayedCount {
+        DecayedCount {
+            scaled: -self.scaled,
+            ..*self
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for DecayedCount {}
+impl AbstractMonoid<Sum> for DecayedCount {}
+infallible!(Sum => DecayedCount);
+impl AbstractQuasigroup<Sum> for DecayedCount {}
+impl AbstractLoop<Sum> for DecayedCount {}
+impl AbstractGroup<Sum> for DecayedCount {}
@@ -0,0 +1,37 @@
+use crate::ops::Sum;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use rust_decimal::Decimal;
+
+// Decimal addition is exact, which makes it safe to subtract on evict.
+
+impl Identity<Sum> for Decimal {
+    fn identity() -> Decimal {
+        Decimal::ZERO
+    }
+}
+
+impl AbstractMagma<Sum> for Decimal {
+    fn operate(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+impl TwoSidedInverse<Sum> for Decimal {
+    fn two_sided_inverse(&self) -> Decimal {
+        -self
+    }
+}
+
+impl AbstractSemigroup<Sum> for Decimal {}
+impl AbstractMonoid<Sum> for Decimal {}
+infallible!(Sum => Decimal);
+impl AbstractQuasigroup<Sum> for Decimal {}
+impl AbstractLoop<Sum> for Decimal {}
+impl AbstractGroup<Sum> for Decimal {}
@@ -0,0 +1,67 @@
+use crate::ops::mode::merge_counts;
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use std::collections::BTreeMap;
+
+/// A partial aggregate of the exact number of distinct values, which is a map from
+/// values to their number of occurrences, and which is lowered into the number of
+/// values with a positive count.
+///
+/// Maps are merged exactly, so the count is exact under every algorithm. Inverted
+/// maps (under subtract-on-evict) have negative counts, which cancel out the counts
+/// of the evicted values.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ExactDistinctCount<T: Ord>(pub BTreeMap<T, i64>);
+
+impl<T: Ord> From<T> for ExactDistinctCount<T> {
+    fn from(v: T) -> Self {
+        let mut counts = BTreeMap::new();
+        counts.insert(v, 1);
+        ExactDistinctCount(counts)
+    }
+}
+
+impl<T: Ord + Clone> Lower<Sum> for ExactDistinctCount<T> {
+    type Output = usize;
+    fn lower(&self) -> usize {
+        self.0.values().filter(|count| **count > 0).count()
+    }
+}
+
+impl<T: Ord + Clone> Identity<Sum> for ExactDistinctCount<T> {
+    fn identity() -> ExactDistinctCount<T> {
+        ExactDistinctCount(BTreeMap::new())
+    }
+}
+
+impl<T: Ord + Clone> AbstractMagma<Sum> for ExactDistinctCount<T> {
+    fn operate(&self, other: &Self) -> Self {
+        ExactDistinctCount(merge_counts(&self.0, &other.0))
+    }
+}
+
+impl<T: Ord + Clone> TwoSidedInverse<Sum> for ExactDistinctCount<T> {
+    fn two_sided_inverse(&self) -> ExactDistinctCount<T> {
+        ExactDistinctCount(
+            self.0
+                .iter()
+                .map(|(v, count)| (v.clone(), -count))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<Sum> for ExactDistinctCount<T> {}
+impl<T: Ord + Clone> AbstractMonoid<Sum> for ExactDistinctCount<T> {}
+infallible!(Sum => {T: Ord + Clone} ExactDistinctCount<T>);
+impl<T: Ord + Clone> AbstractQuasigroup<Sum> for ExactDistinctCount<T> {}
+impl<T: Ord + Clone> AbstractLoop<Sum> for ExactDistinctCount<T> {}
+impl<T: Ord + Clone> AbstractGroup<Sum> for ExactDistinctCount<T> {}
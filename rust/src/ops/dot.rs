@@ -0,0 +1,53 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the dot product of two aligned streams, which are
+/// pushed as pairs `(x, y)`, and which is lowered into an `f64`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct DotProduct(pub f64);
+
+impl From<(f64, f64)> for DotProduct {
+    fn from((x, y): (f64, f64)) -> Self {
+        DotProduct(x * y)
+    }
+}
+
+impl Lower<Sum> for DotProduct {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Identity<Sum> for DotProduct {
+    fn identity() -> DotProduct {
+        DotProduct(0.0)
+    }
+}
+
+impl AbstractMagma<Sum> for DotProduct {
+    fn operate(&self, other: &Self) -> Self {
+        DotProduct(self.0 + other.0)
+    }
+}
+
+impl TwoSidedInverse<Sum> for DotProduct {
+    fn two_sided_inverse(&self) -> DotProduct {
+        DotProduct(-self.0)
+    }
+}
+
+impl AbstractSemigroup<Sum> for DotProduct {}
+impl AbstractMonoid<Sum> for DotProduct {}
+infallible!(Sum => DotProduct);
+impl AbstractQuasigroup<Sum> for DotProduct {}
+impl AbstractLoop<Sum> for DotProduct {}
+impl AbstractGroup<Sum> for DotProduct {}
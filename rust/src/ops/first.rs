@@ -0,0 +1,62 @@
+use crate::ops::Concat;
+use crate::Lower;
+use crate::LowerInto;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the oldest (up to) `N` values of the window in fifo order,
+/// which is lowered into those values.
+///
+/// Two consecutive segments are concatenated by appending the newer segment to the
+/// older one and keeping the first `N` values.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FirstN<T, const N: usize>(pub Vec<T>);
+
+impl<T, const N: usize> From<T> for FirstN<T, N> {
+    fn from(v: T) -> Self {
+        if N == 0 {
+            FirstN(Vec::new())
+        } else {
+            FirstN(vec![v])
+        }
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> Lower<Concat> for FirstN<T, N> {
+    type Output = Vec<T>;
+    fn lower(&self) -> Vec<T> {
+        self.0.clone()
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> LowerInto<Concat> for FirstN<T, N> {
+    fn lower_into(&self, buf: &mut Vec<T>) {
+        buf.clear();
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl<T, const N: usize> Identity<Concat> for FirstN<T, N> {
+    fn identity() -> FirstN<T, N> {
+        FirstN(Vec::new())
+    }
+}
+
+impl<T: Clone, const N: usize> AbstractMagma<Concat> for FirstN<T, N> {
+    fn operate(&self, other: &Self) -> Self {
+        FirstN(
+            self.0
+                .iter()
+                .chain(other.0.iter())
+                .take(N)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> AbstractSemigroup<Concat> for FirstN<T, N> {}
+impl<T: Clone + PartialEq, const N: usize> AbstractMonoid<Concat> for FirstN<T, N> {}
+infallible!(Concat => {T: Clone + PartialEq, const N: usize} FirstN<T, N>);
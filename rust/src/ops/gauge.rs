@@ -0,0 +1,81 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the count, sum, minimum, and maximum of `f64` values,
+/// which is lowered into a `Summary`.
+///
+/// The fields are summed component-wise, where the minimum and maximum are combined
+/// with `f64::min` and `f64::max`. The minimum and maximum cannot be inverted, so a
+/// gauge cannot be aggregated under subtract-on-evict.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Gauge {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// The summary statistics of a window. The mean of an empty window is `NaN`, its
+/// minimum is `+∞`, and its maximum is `-∞`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Summary {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl From<f64> for Gauge {
+    fn from(v: f64) -> Self {
+        Gauge {
+            count: 1,
+            sum: v,
+            min: v,
+            max: v,
+        }
+    }
+}
+
+impl Lower<Sum> for Gauge {
+    type Output = Summary;
+    fn lower(&self) -> Summary {
+        Summary {
+            count: self.count,
+            sum: self.sum,
+            min: self.min,
+            max: self.max,
+            mean: self.sum / self.count as f64,
+        }
+    }
+}
+
+impl Identity<Sum> for Gauge {
+    fn identity() -> Gauge {
+        Gauge {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl AbstractMagma<Sum> for Gauge {
+    fn operate(&self, other: &Self) -> Self {
+        Gauge {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for Gauge {}
+impl AbstractMonoid<Sum> for Gauge {}
+infallible!(Sum => Gauge);
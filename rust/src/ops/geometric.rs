@@ -0,0 +1,88 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the geometric mean of positive values, which is lowered
+/// into an `f64`. The mean of an empty window is `NaN`.
+///
+/// Non-positive values are counted separately instead of taking their logarithm,
+/// so the mean is `NaN` exactly while a non-positive value is inside the window.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct GeometricMean {
+    pub sum_logs: f64,
+    pub count: f64,
+    pub non_positive: f64,
+}
+
+impl From<f64> for GeometricMean {
+    fn from(v: f64) -> Self {
+        if v > 0.0 {
+            GeometricMean {
+                sum_logs: v.ln(),
+                count: 1.0,
+                non_positive: 0.0,
+            }
+        } else {
+            GeometricMean {
+                sum_logs: 0.0,
+                count: 1.0,
+                non_positive: 1.0,
+            }
+        }
+    }
+}
+
+impl Lower<Sum> for GeometricMean {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        if self.non_positive > 0.0 {
+            f64::NAN
+        } else {
+            (self.sum_logs / self.count).exp()
+        }
+    }
+}
+
+impl Identity<Sum> for GeometricMean {
+    fn identity() -> GeometricMean {
+        GeometricMean {
+            sum_logs: 0.0,
+            count: 0.0,
+            non_positive: 0.0,
+        }
+    }
+}
+
+impl AbstractMagma<Sum> for GeometricMean {
+    fn operate(&self, other: &Self) -> Self {
+        GeometricMean {
+            sum_logs: self.sum_logs + other.sum_logs,
+            count: self.count + other.count,
+            non_positive: self.non_positive + other.non_positive,
+        }
+    }
+}
+
+impl TwoSidedInverse<Sum> for GeometricMean {
+    fn two_sided_inverse(&self) -> GeometricMean {
+        GeometricMean {
+            sum_logs: -self.sum_logs,
+            count: -self.count,
+            non_positive: -self.non_positive,
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for GeometricMean {}
+impl AbstractMonoid<Sum> for GeometricMean {}
+infallible!(Sum => GeometricMean);
+impl AbstractQuasigroup<Sum> for GeometricMean {}
+impl AbstractLoop<Sum> for GeometricMean {}
+impl AbstractGroup<Sum> for GeometricMean {}
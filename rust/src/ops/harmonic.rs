@@ -0,0 +1,88 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the harmonic mean, which is lowered into an `f64`.
+/// The mean of an empty window is `NaN`.
+///
+/// Zeros are counted separately instead of taking their reciprocal, and the
+/// mean is zero while a zero is inside the window.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct HarmonicMean {
+    pub sum_reciprocals: f64,
+    pub count: f64,
+    pub zeros: f64,
+}
+
+impl From<f64> for HarmonicMean {
+    fn from(v: f64) -> Self {
+        if v != 0.0 {
+            HarmonicMean {
+                sum_reciprocals: 1.0 / v,
+                count: 1.0,
+                zeros: 0.0,
+            }
+        } else {
+            HarmonicMean {
+                sum_reciprocals: 0.0,
+                count: 1.0,
+                zeros: 1.0,
+            }
+        }
+    }
+}
+
+impl Lower<Sum> for HarmonicMean {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        if self.zeros > 0.0 {
+            0.0
+        } else {
+            self.count / self.sum_reciprocals
+        }
+    }
+}
+
+impl Identity<Sum> for HarmonicMean {
+    fn identity() -> HarmonicMean {
+        HarmonicMean {
+            sum_reciprocals: 0.0,
+            count: 0.0,
+            zeros: 0.0,
+        }
+    }
+}
+
+impl AbstractMagma<Sum> for HarmonicMean {
+    fn operate(&self, other: &Self) -> Self {
+        HarmonicMean {
+            sum_reciprocals: self.sum_reciprocals + other.sum_reciprocals,
+            count: self.count + other.count,
+            zeros: self.zeros + other.zeros,
+        }
+    }
+}
+
+impl TwoSidedInverse<Sum> for HarmonicMean {
+    fn two_sided_inverse(&self) -> HarmonicMean {
+        HarmonicMean {
+            sum_reciprocals: -self.sum_reciprocals,
+            count: -self.count,
+            zeros: -self.zeros,
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for HarmonicMean {}
+impl AbstractMonoid<Sum> for HarmonicMean {}
+infallible!(Sum => HarmonicMean);
+impl AbstractQuasigroup<Sum> for HarmonicMean {}
+impl AbstractLoop<Sum> for HarmonicMean {}
+impl AbstractGroup<Sum> for HarmonicMean {}
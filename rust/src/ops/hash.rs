@@ -0,0 +1,97 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// The Mersenne prime `2^61 - 1`, which is the modulus of the hash.
+const MODULUS: u64 = (1 << 61) - 1;
+
+/// The base of the hash polynomial.
+const BASE: u64 = 0x5bd1_e995;
+
+/// A partial aggregate of the Rabin-Karp hash `v_1*B^(n-1) + ... + v_n*B^0` of the
+/// values of the window modulo a prime, which is lowered into the hash.
+///
+/// Two consecutive segments are concatenated by shifting the hash of the older segment
+/// by the power `B^n` of the newer segment, and adding the hash of the newer segment.
+/// Since the base is invertible modulo the prime, partials form a (non-commutative)
+/// group, so the hash can be updated in `O(1)` under subtract-on-evict.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RollingHash {
+    pub hash: u64,
+    /// The base raised to the number of values of the segment.
+    pub power: u64,
+}
+
+/// Returns `a * b` modulo the prime.
+fn mul(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % MODULUS as u128) as u64
+}
+
+/// Returns `a` raised to `e` modulo the prime.
+fn pow(mut a: u64, mut e: u64) -> u64 {
+    let mut acc = 1;
+    while e > 0 {
+        if e % 2 == 1 {
+            acc = mul(acc, a);
+        }
+        a = mul(a, a);
+        e /= 2;
+    }
+    acc
+}
+
+impl From<u64> for RollingHash {
+    fn from(v: u64) -> Self {
+        RollingHash {
+            hash: v % MODULUS,
+            power: BASE,
+        }
+    }
+}
+
+impl Lower<Concat> for RollingHash {
+    type Output = u64;
+    fn lower(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl Identity<Concat> for RollingHash {
+    fn identity() -> RollingHash {
+        RollingHash { hash: 0, power: 1 }
+    }
+}
+
+impl AbstractMagma<Concat> for RollingHash {
+    fn operate(&self, other: &Self) -> Self {
+        RollingHash {
+            hash: (mul(self.hash, other.power) + other.hash) % MODULUS,
+            power: mul(self.power, other.power),
+        }
+    }
+}
+
+impl TwoSidedInverse<Concat> for RollingHash {
+    fn two_sided_inverse(&self) -> RollingHash {
+        // By Fermat's little theorem, the inverse of the power is `power^(p-2)`.
+        let power = pow(self.power, MODULUS - 2);
+        RollingHash {
+            hash: (MODULUS - mul(self.hash, power)) % MODULUS,
+            power,
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for RollingHash {}
+impl AbstractMonoid<Concat> for RollingHash {}
+infallible!(Concat => RollingHash);
+impl AbstractQuasigroup<Concat> for RollingHash {}
+impl AbstractLoop<Concat> for RollingHash {}
+impl AbstractGroup<Concat> for RollingHash {}
@@ -0,0 +1,48 @@
+use crate::ops::Sum;
+use crate::ops::TDigest;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of `f64` values as a t-digest, which is lowered into an estimate
+/// of their interquartile range, i.e., the difference between the 75th and the 25th
+/// percentile. The range is `NaN` if the window is empty.
+///
+/// Both quartiles are estimated from the same digest, so the partials are summed by
+/// merging their digests like `TDigest` does.
+#[derive(Clone, PartialEq, Debug)]
+pub struct InterquartileRange(pub TDigest);
+
+impl From<f64> for InterquartileRange {
+    fn from(v: f64) -> Self {
+        InterquartileRange(TDigest::with_value(v))
+    }
+}
+
+impl Lower<Sum> for InterquartileRange {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        match (self.0.quantile(0.25), self.0.quantile(0.75)) {
+            (Some(q25), Some(q75)) => q75 - q25,
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl Identity<Sum> for InterquartileRange {
+    fn identity() -> InterquartileRange {
+        InterquartileRange(TDigest::identity())
+    }
+}
+
+impl AbstractMagma<Sum> for InterquartileRange {
+    fn operate(&self, other: &Self) -> Self {
+        InterquartileRange(self.0.operate(&other.0))
+    }
+}
+
+impl AbstractSemigroup<Sum> for InterquartileRange {}
+impl AbstractMonoid<Sum> for InterquartileRange {}
+infallible!(Sum => InterquartileRange);
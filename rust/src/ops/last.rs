@@ -0,0 +1,63 @@
+use crate::ops::Concat;
+use crate::Lower;
+use crate::LowerInto;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the most recent (up to) `N` values of the window in fifo
+/// order, which is lowered into those values.
+///
+/// Two consecutive segments are concatenated by appending the newer segment to the
+/// older one and keeping the last `N` values.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LastN<T, const N: usize>(pub Vec<T>);
+
+impl<T, const N: usize> From<T> for LastN<T, N> {
+    fn from(v: T) -> Self {
+        if N == 0 {
+            LastN(Vec::new())
+        } else {
+            LastN(vec![v])
+        }
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> Lower<Concat> for LastN<T, N> {
+    type Output = Vec<T>;
+    fn lower(&self) -> Vec<T> {
+        self.0.clone()
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> LowerInto<Concat> for LastN<T, N> {
+    fn lower_into(&self, buf: &mut Vec<T>) {
+        buf.clear();
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl<T, const N: usize> Identity<Concat> for LastN<T, N> {
+    fn identity() -> LastN<T, N> {
+        LastN(Vec::new())
+    }
+}
+
+impl<T: Clone, const N: usize> AbstractMagma<Concat> for LastN<T, N> {
+    fn operate(&self, other: &Self) -> Self {
+        let skip = (self.0.len() + other.0.len()).saturating_sub(N);
+        LastN(
+            self.0
+                .iter()
+                .chain(other.0.iter())
+                .skip(skip)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> AbstractSemigroup<Concat> for LastN<T, N> {}
+impl<T: Clone + PartialEq, const N: usize> AbstractMonoid<Concat> for LastN<T, N> {}
+infallible!(Concat => {T: Clone + PartialEq, const N: usize} LastN<T, N>);
@@ -0,0 +1,55 @@
+use crate::Semigroup;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+
+/// A value of a semigroup which lacks an identity, where `None` represents the
+/// aggregate of an empty window. This turns any semigroup into a monoid.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Maybe<Value>(pub Option<Value>);
+
+impl<Value> From<Value> for Maybe<Value> {
+    fn from(v: Value) -> Self {
+        Maybe(Some(v))
+    }
+}
+
+impl<Value, BinOp> Identity<BinOp> for Maybe<Value>
+where
+    Value: Semigroup<BinOp>,
+    BinOp: Operator,
+{
+    fn identity() -> Maybe<Value> {
+        Maybe(None)
+    }
+}
+
+impl<Value, BinOp> AbstractMagma<BinOp> for Maybe<Value>
+where
+    Value: Semigroup<BinOp>,
+    BinOp: Operator,
+{
+    fn operate(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Maybe(Some(a.operate(b))),
+            (Some(a), None) => Maybe(Some(a.clone())),
+            (None, b) => Maybe(b.clone()),
+        }
+    }
+}
+
+impl<Value, BinOp> AbstractSemigroup<BinOp> for Maybe<Value>
+where
+    Value: Semigroup<BinOp>,
+    BinOp: Operator,
+{
+}
+
+impl<Value, BinOp> AbstractMonoid<BinOp> for Maybe<Value>
+where
+    Value: Semigroup<BinOp>,
+    BinOp: Operator,
+{
+}
@@ -0,0 +1,65 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the arithmetic mean, which is lowered into an `f64`.
+/// The mean of an empty window is `NaN`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Mean {
+    pub sum: f64,
+    pub count: f64,
+}
+
+impl From<f64> for Mean {
+    fn from(v: f64) -> Self {
+        Mean { sum: v, count: 1.0 }
+    }
+}
+
+impl Lower<Sum> for Mean {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        self.sum / self.count
+    }
+}
+
+impl Identity<Sum> for Mean {
+    fn identity() -> Mean {
+        Mean {
+            sum: 0.0,
+            count: 0.0,
+        }
+    }
+}
+
+impl AbstractMagma<Sum> for Mean {
+    fn operate(&self, other: &Self) -> Self {
+        Mean {
+            sum: self.sum + other.sum,
+            count: self.count + other.count,
+        }
+    }
+}
+
+impl TwoSidedInverse<Sum> for Mean {
+    fn two_sided_inverse(&self) -> Mean {
+        Mean {
+            sum: -self.sum,
+            count: -self.count,
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for Mean {}
+impl AbstractMonoid<Sum> for Mean {}
+infallible!(Sum => Mean);
+impl AbstractQuasigroup<Sum> for Mean {}
+impl AbstractLoop<Sum> for Mean {}
+impl AbstractGroup<Sum> for Mean {}
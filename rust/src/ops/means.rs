@@ -0,0 +1,89 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the count, sum, sum of logarithms, and sum of reciprocals of
+/// positive `f64` values, which is lowered into their `Means`.
+///
+/// All fields are summed component-wise and inverted by negation, so the bundle can
+/// be aggregated under subtract-on-evict.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct MeanBundle {
+    pub count: f64,
+    pub sum: f64,
+    pub sum_ln: f64,
+    pub sum_recip: f64,
+}
+
+/// The arithmetic, geometric, and harmonic mean of a window, which are all `NaN` if
+/// the window is empty.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Means {
+    pub arithmetic: f64,
+    pub geometric: f64,
+    pub harmonic: f64,
+}
+
+impl From<f64> for MeanBundle {
+    fn from(v: f64) -> Self {
+        MeanBundle {
+            count: 1.0,
+            sum: v,
+            sum_ln: v.ln(),
+            sum_recip: v.recip(),
+        }
+    }
+}
+
+impl Lower<Sum> for MeanBundle {
+    type Output = Means;
+    fn lower(&self) -> Means {
+        Means {
+            arithmetic: self.sum / self.count,
+            geometric: (self.sum_ln / self.count).exp(),
+            harmonic: self.count / self.sum_recip,
+        }
+    }
+}
+
+impl Identity<Sum> for MeanBundle {
+    fn identity() -> MeanBundle {
+        MeanBundle::default()
+    }
+}
+
+impl AbstractMagma<Sum> for MeanBundle {
+    fn operate(&self, other: &Self) -> Self {
+        MeanBundle {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            sum_ln: self.sum_ln + other.sum_ln,
+            sum_recip: self.sum_recip + other.sum_recip,
+        }
+    }
+}
+
+impl TwoSidedInverse<Sum> for MeanBundle {
+    fn two_sided_inverse(&self) -> MeanBundle {
+        MeanBundle {
+            count: -self.count,
+            sum: -self.sum,
+            sum_ln: -self.sum_ln,
+            sum_recip: -self.sum_recip,
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for MeanBundle {}
+impl AbstractMonoid<Sum> for MeanBundle {}
+infallible!(Sum => MeanBundle);
+impl AbstractQuasigroup<Sum> for MeanBundle {}
+impl AbstractLoop<Sum> for MeanBundle {}
+impl AbstractGroup<Sum> for MeanBundle {}
@@ -0,0 +1,190 @@
+use alga::general::Operator;
+use std::cmp::Ordering;
+
+mod above;
+mod any;
+mod autocorr;
+mod by;
+mod checked;
+mod complex;
+mod countmin;
+mod covariance;
+mod crossing;
+mod decay;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+mod distinct;
+mod dot;
+mod first;
+mod gauge;
+mod geometric;
+mod harmonic;
+mod hash;
+mod iqr;
+mod last;
+mod maybe;
+mod mean;
+mod means;
+mod mode;
+mod order;
+mod pair;
+mod peaks;
+mod product;
+mod range;
+mod rank;
+mod run;
+mod safe;
+mod sorted;
+mod subarray;
+mod sum;
+mod sum_count;
+mod tdigest;
+mod top;
+mod variance;
+mod variation;
+mod weighted;
+
+pub use above::FirstAbove;
+pub use any::Any;
+pub use autocorr::AutoCorr;
+pub use by::By;
+pub use by::Key;
+pub use by::MaxBy;
+pub use by::MinBy;
+pub use checked::CheckedSum;
+pub use complex::Complex;
+pub use countmin::CountMin;
+pub use covariance::CovarianceMatrix;
+pub use crossing::CrossingRate;
+pub use decay::DecayedCount;
+pub use distinct::ExactDistinctCount;
+pub use dot::DotProduct;
+pub use first::FirstN;
+pub use gauge::Gauge;
+pub use gauge::Summary;
+pub use geometric::GeometricMean;
+pub use harmonic::HarmonicMean;
+pub use hash::RollingHash;
+pub use iqr::InterquartileRange;
+pub use last::LastN;
+pub use maybe::Maybe;
+pub use mean::Mean;
+pub use means::MeanBundle;
+pub use means::Means;
+pub use mode::Mode;
+pub use order::Ordered;
+pub use pair::Pair;
+pub use peaks::PeakCount;
+pub use product::ZeroCountProduct;
+pub use range::CountInRange;
+pub use rank::Rank;
+pub use run::LongestRun;
+pub use safe::SafeMax;
+pub use safe::SafeMin;
+pub use sorted::CollectSorted;
+pub use subarray::MaxSubarraySum;
+pub use sum_count::SumCount;
+pub use tdigest::TDigest;
+pub use top::Top;
+pub use top::TopKBy;
+pub use variance::InvertibleVariance;
+pub use variance::Variance;
+pub use variation::TotalVariation;
+pub use weighted::WeightedSum;
+
+/// Binary operator for calculating the arithmetic sum.
+/// Has the following properties:
+/// * Invertibility
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct Sum;
+
+impl Operator for Sum {
+    fn operator_token() -> Sum {
+        Sum
+    }
+}
+
+/// Binary operator for calculating the arithmetic product.
+/// Has the following properties:
+/// * Invertibility (of non-zero values)
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct Product;
+
+impl Operator for Product {
+    fn operator_token() -> Product {
+        Product
+    }
+}
+
+/// Binary operator for concatenating two consecutive segments of the window.
+/// Has the following properties:
+/// * Associativity
+#[derive(Copy, Clone)]
+pub struct Concat;
+
+impl Operator for Concat {
+    fn operator_token() -> Concat {
+        Concat
+    }
+}
+
+/// Binary operator for calculating the maximum.
+/// Has the following properties:
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct Max;
+
+impl Operator for Max {
+    fn operator_token() -> Max {
+        Max
+    }
+}
+
+/// Binary operator for calculating the minimum.
+/// Has the following properties:
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct Min;
+
+impl Operator for Min {
+    fn operator_token() -> Min {
+        Min
+    }
+}
+
+/// A binary operator for which the order of operands does not matter, i.e.,
+/// `a + b = b + a` for all values `a` and `b`.
+pub trait Commutative: Operator {}
+
+impl Commutative for Sum {}
+impl Commutative for Product {}
+impl Commutative for Max {}
+impl Commutative for Min {}
+impl Commutative for SafeMax {}
+impl Commutative for SafeMin {}
+impl<A: Commutative, B: Commutative> Commutative for Pair<A, B> {}
+
+/// A binary operator which aggregates two values by selecting one of them
+/// according to an order, so that it is always equal to one of its operands.
+pub trait Selection: Operator {
+    /// Returns `Greater` if `a` is selected over `b`, and `Less` if `b` is selected over `a`.
+    fn cmp<T: Ord>(a: &T, b: &T) -> Ordering;
+}
+
+impl Selection for Max {
+    fn cmp<T: Ord>(a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl Selection for Min {
+    fn cmp<T: Ord>(a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
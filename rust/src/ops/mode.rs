@@ -0,0 +1,93 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use std::collections::BTreeMap;
+
+/// A partial aggregate of the most frequent value, which is a map from values to
+/// their number of occurrences, and which is lowered into the value with the
+/// highest count (or `None` if the window is empty). Ties are broken toward the
+/// smallest value.
+///
+/// Counts are signed so that maps can be inverted, and values whose count
+/// becomes zero are removed.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Mode<T: Ord>(pub BTreeMap<T, i64>);
+
+impl<T: Ord> From<T> for Mode<T> {
+    fn from(v: T) -> Self {
+        let mut counts = BTreeMap::new();
+        counts.insert(v, 1);
+        Mode(counts)
+    }
+}
+
+impl<T: Ord + Clone> Lower<Sum> for Mode<T> {
+    type Output = Option<T>;
+    fn lower(&self) -> Option<T> {
+        let mut mode: Option<(&T, i64)> = None;
+        for (v, count) in self.0.iter() {
+            match mode {
+                Some((_, max)) if *count <= max => {}
+                _ => mode = Some((v, *count)),
+            }
+        }
+        mode.map(|(v, _)| v.clone())
+    }
+}
+
+impl<T: Ord + Clone> Identity<Sum> for Mode<T> {
+    fn identity() -> Mode<T> {
+        Mode(BTreeMap::new())
+    }
+}
+
+/// Adds the counts of two maps, and removes the values whose count becomes zero.
+pub(crate) fn merge_counts<T: Ord + Clone>(
+    a: &BTreeMap<T, i64>,
+    b: &BTreeMap<T, i64>,
+) -> BTreeMap<T, i64> {
+    let (mut large, small) = if a.len() >= b.len() {
+        (a.clone(), b)
+    } else {
+        (b.clone(), a)
+    };
+    for (v, count) in small.iter() {
+        let entry = large.entry(v.clone()).or_insert(0);
+        *entry += count;
+        if *entry == 0 {
+            large.remove(v);
+        }
+    }
+    large
+}
+
+impl<T: Ord + Clone> AbstractMagma<Sum> for Mode<T> {
+    fn operate(&self, other: &Self) -> Self {
+        Mode(merge_counts(&self.0, &other.0))
+    }
+}
+
+impl<T: Ord + Clone> TwoSidedInverse<Sum> for Mode<T> {
+    fn two_sided_inverse(&self) -> Mode<T> {
+        Mode(
+            self.0
+                .iter()
+                .map(|(v, count)| (v.clone(), -count))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<Sum> for Mode<T> {}
+impl<T: Ord + Clone> AbstractMonoid<Sum> for Mode<T> {}
+infallible!(Sum => {T: Ord + Clone} Mode<T>);
+impl<T: Ord + Clone> AbstractQuasigroup<Sum> for Mode<T> {}
+impl<T: Ord + Clone> AbstractLoop<Sum> for Mode<T> {}
+impl<T: Ord + Clone> AbstractGroup<Sum> for Mode<T> {}
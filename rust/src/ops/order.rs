@@ -0,0 +1,92 @@
+use crate::ops::Max;
+use crate::ops::Min;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use num_traits::Bounded;
+
+/// Implements `Max` and `Min` for primitive integers, whose identities are
+/// the bounds of the type.
+macro_rules! impl_order {
+    { $($ty:ty),* } => {
+        $(
+            impl Identity<Max> for $ty {
+                fn identity() -> $ty {
+                    <$ty as Bounded>::min_value()
+                }
+            }
+
+            impl AbstractMagma<Max> for $ty {
+                fn operate(&self, other: &Self) -> Self {
+                    std::cmp::max(*self, *other)
+                }
+            }
+
+            impl AbstractSemigroup<Max> for $ty {}
+            impl AbstractMonoid<Max> for $ty {}
+            infallible!(Max => $ty);
+
+            impl Identity<Min> for $ty {
+                fn identity() -> $ty {
+                    <$ty as Bounded>::max_value()
+                }
+            }
+
+            impl AbstractMagma<Min> for $ty {
+                fn operate(&self, other: &Self) -> Self {
+                    std::cmp::min(*self, *other)
+                }
+            }
+
+            impl AbstractSemigroup<Min> for $ty {}
+            impl AbstractMonoid<Min> for $ty {}
+            infallible!(Min => $ty);
+        )*
+    }
+}
+
+impl_order! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+
+/// A value of any bounded and ordered type `T`, whose identity under `Max` is the
+/// smallest value of `T`, and whose identity under `Min` is the largest value of `T`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Ordered<T>(pub T);
+
+impl<T> From<T> for Ordered<T> {
+    fn from(v: T) -> Self {
+        Ordered(v)
+    }
+}
+
+impl<T: Bounded + Ord + Clone> Identity<Max> for Ordered<T> {
+    fn identity() -> Ordered<T> {
+        Ordered(T::min_value())
+    }
+}
+
+impl<T: Bounded + Ord + Clone> AbstractMagma<Max> for Ordered<T> {
+    fn operate(&self, other: &Self) -> Self {
+        std::cmp::max(self, other).clone()
+    }
+}
+
+impl<T: Bounded + Ord + Clone> AbstractSemigroup<Max> for Ordered<T> {}
+impl<T: Bounded + Ord + Clone> AbstractMonoid<Max> for Ordered<T> {}
+infallible!(Max => {T: Bounded + Ord + Clone} Ordered<T>);
+
+impl<T: Bounded + Ord + Clone> Identity<Min> for Ordered<T> {
+    fn identity() -> Ordered<T> {
+        Ordered(T::max_value())
+    }
+}
+
+impl<T: Bounded + Ord + Clone> AbstractMagma<Min> for Ordered<T> {
+    fn operate(&self, other: &Self) -> Self {
+        std::cmp::min(self, other).clone()
+    }
+}
+
+impl<T: Bounded + Ord + Clone> AbstractSemigroup<Min> for Ordered<T> {}
+impl<T: Bounded + Ord + Clone> AbstractMonoid<Min> for Ordered<T> {}
+infallible!(Min => {T: Bounded + Ord + Clone} Ordered<T>);
@@ -0,0 +1,108 @@
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use alga::general::TwoSidedInverse;
+use std::marker::PhantomData;
+
+/// Binary operator which applies `A` and `B` component-wise to a pair `(x, y)`,
+/// i.e., `(x1, y1) + (x2, y2) = (x1 A x2, y1 B y2)`.
+/// Pairs can be nested to aggregate more than two values, e.g., `Pair<A, Pair<B, C>>`.
+/// Inherits the properties which both `A` and `B` have.
+#[derive(Copy, Clone)]
+pub struct Pair<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> Operator for Pair<A, B>
+where
+    A: Operator,
+    B: Operator,
+{
+    fn operator_token() -> Pair<A, B> {
+        Pair(PhantomData)
+    }
+}
+
+impl<X, Y, A, B> Identity<Pair<A, B>> for (X, Y)
+where
+    X: Identity<A>,
+    Y: Identity<B>,
+    A: Operator,
+    B: Operator,
+{
+    fn identity() -> (X, Y) {
+        (X::identity(), Y::identity())
+    }
+}
+
+impl<X, Y, A, B> AbstractMagma<Pair<A, B>> for (X, Y)
+where
+    X: AbstractMagma<A>,
+    Y: AbstractMagma<B>,
+    A: Operator,
+    B: Operator,
+{
+    fn operate(&self, other: &Self) -> Self {
+        (self.0.operate(&other.0), self.1.operate(&other.1))
+    }
+}
+
+impl<X, Y, A, B> TwoSidedInverse<Pair<A, B>> for (X, Y)
+where
+    X: TwoSidedInverse<A>,
+    Y: TwoSidedInverse<B>,
+    A: Operator,
+    B: Operator,
+{
+    fn two_sided_inverse(&self) -> (X, Y) {
+        (self.0.two_sided_inverse(), self.1.two_sided_inverse())
+    }
+}
+
+impl<X, Y, A, B> AbstractSemigroup<Pair<A, B>> for (X, Y)
+where
+    X: AbstractSemigroup<A>,
+    Y: AbstractSemigroup<B>,
+    A: Operator,
+    B: Operator,
+{
+}
+
+impl<X, Y, A, B> AbstractMonoid<Pair<A, B>> for (X, Y)
+where
+    X: AbstractMonoid<A>,
+    Y: AbstractMonoid<B>,
+    A: Operator,
+    B: Operator,
+{
+}
+
+impl<X, Y, A, B> AbstractQuasigroup<Pair<A, B>> for (X, Y)
+where
+    X: AbstractQuasigroup<A>,
+    Y: AbstractQuasigroup<B>,
+    A: Operator,
+    B: Operator,
+{
+}
+
+impl<X, Y, A, B> AbstractLoop<Pair<A, B>> for (X, Y)
+where
+    X: AbstractLoop<A>,
+    Y: AbstractLoop<B>,
+    A: Operator,
+    B: Operator,
+{
+}
+
+impl<X, Y, A, B> AbstractGroup<Pair<A, B>> for (X, Y)
+where
+    X: AbstractGroup<A>,
+    Y: AbstractGroup<B>,
+    A: Operator,
+    B: Operator,
+{
+}
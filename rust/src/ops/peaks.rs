@@ -0,0 +1,87 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the number of peaks in the window, i.e., values which are
+/// greater than both of their neighbors, which is lowered into that number.
+///
+/// Two consecutive segments are concatenated by adding their peaks and the peaks at the
+/// boundary, where the last value of the older segment and the first value of the newer
+/// segment are compared to their neighbors in the other segment. Every value is counted
+/// once, when it is first concatenated with both of its neighbors.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct PeakCount {
+    /// The (up to) two first values of the segment.
+    pub head: Vec<i64>,
+    /// The (up to) two last values of the segment.
+    pub tail: Vec<i64>,
+    pub peaks: usize,
+}
+
+impl From<i64> for PeakCount {
+    fn from(v: i64) -> Self {
+        PeakCount {
+            head: vec![v],
+            tail: vec![v],
+            peaks: 0,
+        }
+    }
+}
+
+impl Lower<Concat> for PeakCount {
+    type Output = usize;
+    fn lower(&self) -> usize {
+        self.peaks
+    }
+}
+
+impl Identity<Concat> for PeakCount {
+    fn identity() -> PeakCount {
+        PeakCount::default()
+    }
+}
+
+impl AbstractMagma<Concat> for PeakCount {
+    fn operate(&self, other: &Self) -> Self {
+        if other.head.is_empty() {
+            return self.clone();
+        }
+        if self.head.is_empty() {
+            return other.clone();
+        }
+        let boundary = self
+            .tail
+            .iter()
+            .chain(other.head.iter())
+            .collect::<Vec<_>>();
+        let is_peak = |i: usize| {
+            0 < i
+                && i + 1 < boundary.len()
+                && boundary[i - 1] < boundary[i]
+                && boundary[i] > boundary[i + 1]
+        };
+        let last = self.tail.len() - 1;
+        let peaks = is_peak(last) as usize + is_peak(last + 1) as usize;
+        let head = self
+            .head
+            .iter()
+            .chain(other.head.iter())
+            .take(2)
+            .cloned()
+            .collect();
+        let tail = self.tail.iter().chain(other.tail.iter());
+        let skip = (self.tail.len() + other.tail.len()).saturating_sub(2);
+        PeakCount {
+            head,
+            tail: tail.skip(skip).cloned().collect(),
+            peaks: self.peaks + other.peaks + peaks,
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for PeakCount {}
+impl AbstractMonoid<Concat> for PeakCount {}
+infallible!(Concat => PeakCount);
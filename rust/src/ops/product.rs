@@ -0,0 +1,82 @@
+use crate::ops::Product;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the product, which is lowered into an `f64`.
+///
+/// Zeros are counted separately instead of being multiplied, so the product is
+/// zero while a zero is inside the window, and recovers the product of the other
+/// values once all zeros have been evicted. This makes the product invertible.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ZeroCountProduct {
+    pub non_zero: f64,
+    pub zeros: i64,
+}
+
+impl From<f64> for ZeroCountProduct {
+    fn from(v: f64) -> Self {
+        if v == 0.0 {
+            ZeroCountProduct {
+                non_zero: 1.0,
+                zeros: 1,
+            }
+        } else {
+            ZeroCountProduct {
+                non_zero: v,
+                zeros: 0,
+            }
+        }
+    }
+}
+
+impl Lower<Product> for ZeroCountProduct {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        if self.zeros > 0 {
+            0.0
+        } else {
+            self.non_zero
+        }
+    }
+}
+
+impl Identity<Product> for ZeroCountProduct {
+    fn identity() -> ZeroCountProduct {
+        ZeroCountProduct {
+            non_zero: 1.0,
+            zeros: 0,
+        }
+    }
+}
+
+impl AbstractMagma<Product> for ZeroCountProduct {
+    fn operate(&self, other: &Self) -> Self {
+        ZeroCountProduct {
+            non_zero: self.non_zero * other.non_zero,
+            zeros: self.zeros + other.zeros,
+        }
+    }
+}
+
+impl TwoSidedInverse<Product> for ZeroCountProduct {
+    fn two_sided_inverse(&self) -> ZeroCountProduct {
+        ZeroCountProduct {
+            non_zero: 1.0 / self.non_zero,
+            zeros: -self.zeros,
+        }
+    }
+}
+
+impl AbstractSemigroup<Product> for ZeroCountProduct {}
+impl AbstractMonoid<Product> for ZeroCountProduct {}
+infallible!(Product => ZeroCountProduct);
+impl AbstractQuasigroup<Product> for ZeroCountProduct {}
+impl AbstractLoop<Product> for ZeroCountProduct {}
+impl AbstractGroup<Product> for ZeroCountProduct {}
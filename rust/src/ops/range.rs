@@ -0,0 +1,46 @@
+use crate::ops::Sum;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use std::ops::RangeInclusive;
+
+/// The number of values which fall within an interval `[lo, hi]`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct CountInRange(pub i64);
+
+impl CountInRange {
+    /// Lifts `v` into a count of one if it falls within `range`, and zero otherwise.
+    pub fn lift<T: PartialOrd>(v: &T, range: &RangeInclusive<T>) -> Self {
+        CountInRange(range.contains(v) as i64)
+    }
+}
+
+impl Identity<Sum> for CountInRange {
+    fn identity() -> CountInRange {
+        CountInRange(0)
+    }
+}
+
+impl AbstractMagma<Sum> for CountInRange {
+    fn operate(&self, other: &Self) -> Self {
+        CountInRange(self.0 + other.0)
+    }
+}
+
+impl TwoSidedInverse<Sum> for CountInRange {
+    fn two_sided_inverse(&self) -> CountInRange {
+        CountInRange(-self.0)
+    }
+}
+
+impl AbstractSemigroup<Sum> for CountInRange {}
+impl AbstractMonoid<Sum> for CountInRange {}
+infallible!(Sum => CountInRange);
+impl AbstractQuasigroup<Sum> for CountInRange {}
+impl AbstractLoop<Sum> for CountInRange {}
+impl AbstractGroup<Sum> for CountInRange {}
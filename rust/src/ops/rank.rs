@@ -0,0 +1,53 @@
+use crate::ops::Sum;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the values of the window as a sorted multiset, which answers
+/// rank queries for a probe value in `O(log n)` by binary search.
+///
+/// Two multisets are summed by merging them in linear time.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Rank<T>(pub Vec<T>);
+
+impl<T: Ord> Rank<T> {
+    /// Returns the number of values which are less than or equal to `probe`.
+    pub fn rank(&self, probe: &T) -> usize {
+        self.0.partition_point(|v| v <= probe)
+    }
+}
+
+impl<T> From<T> for Rank<T> {
+    fn from(v: T) -> Self {
+        Rank(vec![v])
+    }
+}
+
+impl<T> Identity<Sum> for Rank<T> {
+    fn identity() -> Rank<T> {
+        Rank(Vec::new())
+    }
+}
+
+impl<T: Ord + Clone> AbstractMagma<Sum> for Rank<T> {
+    fn operate(&self, other: &Self) -> Self {
+        let mut merged = Vec::with_capacity(self.0.len() + other.0.len());
+        let mut a = self.0.iter().peekable();
+        let mut b = other.0.iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if x <= y {
+                merged.extend(a.next().cloned());
+            } else {
+                merged.extend(b.next().cloned());
+            }
+        }
+        merged.extend(a.cloned());
+        merged.extend(b.cloned());
+        Rank(merged)
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<Sum> for Rank<T> {}
+impl<T: Ord + Clone> AbstractMonoid<Sum> for Rank<T> {}
+infallible!(Sum => {T: Ord + Clone} Rank<T>);
@@ -0,0 +1,87 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the longest strictly-increasing contiguous run of values in
+/// the window, which is lowered into the length of that run.
+///
+/// Two consecutive segments are concatenated by stitching the increasing suffix of the
+/// older segment to the increasing prefix of the newer segment if the last value of the
+/// older segment is less than the first value of the newer segment.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct LongestRun {
+    /// The first and last value of the segment, or `None` if it is empty.
+    pub ends: Option<(i64, i64)>,
+    /// The length of the increasing run which starts at the first value.
+    pub prefix: usize,
+    /// The length of the increasing run which ends at the last value.
+    pub suffix: usize,
+    pub len: usize,
+    pub best: usize,
+}
+
+impl From<i64> for LongestRun {
+    fn from(v: i64) -> Self {
+        LongestRun {
+            ends: Some((v, v)),
+            prefix: 1,
+            suffix: 1,
+            len: 1,
+            best: 1,
+        }
+    }
+}
+
+impl Lower<Concat> for LongestRun {
+    type Output = usize;
+    fn lower(&self) -> usize {
+        self.best
+    }
+}
+
+impl Identity<Concat> for LongestRun {
+    fn identity() -> LongestRun {
+        LongestRun::default()
+    }
+}
+
+impl AbstractMagma<Concat> for LongestRun {
+    fn operate(&self, other: &Self) -> Self {
+        match (self.ends, other.ends) {
+            (Some((first, last)), Some((next, newest))) => {
+                let joined = last < next;
+                let prefix = if joined && self.prefix == self.len {
+                    self.len + other.prefix
+                } else {
+                    self.prefix
+                };
+                let suffix = if joined && other.suffix == other.len {
+                    self.suffix + other.len
+                } else {
+                    other.suffix
+                };
+                let stitched = if joined {
+                    self.suffix + other.prefix
+                } else {
+                    0
+                };
+                LongestRun {
+                    ends: Some((first, newest)),
+                    prefix,
+                    suffix,
+                    len: self.len + other.len,
+                    best: self.best.max(other.best).max(stitched),
+                }
+            }
+            (_, None) => *self,
+            (None, _) => *other,
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for LongestRun {}
+impl AbstractMonoid<Concat> for LongestRun {}
+infallible!(Concat => LongestRun);
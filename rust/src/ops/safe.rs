@@ -0,0 +1,74 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+
+/// Binary operator for calculating the maximum of optional values, where `None` is
+/// the aggregate of an empty window. Unlike `Max`, the identity is not a bound of the
+/// value type, so a window which contains e.g. `i64::MIN` is not mistaken for empty.
+/// Has the following properties:
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct SafeMax;
+
+impl Operator for SafeMax {
+    fn operator_token() -> SafeMax {
+        SafeMax
+    }
+}
+
+/// Binary operator for calculating the minimum of optional values, where `None` is
+/// the aggregate of an empty window, like `SafeMax`.
+/// Has the following properties:
+/// * Associativity
+/// * Commutativity
+#[derive(Copy, Clone)]
+pub struct SafeMin;
+
+impl Operator for SafeMin {
+    fn operator_token() -> SafeMin {
+        SafeMin
+    }
+}
+
+impl<T: Ord + Clone> Identity<SafeMax> for Option<T> {
+    fn identity() -> Option<T> {
+        None
+    }
+}
+
+impl<T: Ord + Clone> AbstractMagma<SafeMax> for Option<T> {
+    fn operate(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(std::cmp::max(a, b).clone()),
+            (a, None) => a.clone(),
+            (None, b) => b.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<SafeMax> for Option<T> {}
+impl<T: Ord + Clone> AbstractMonoid<SafeMax> for Option<T> {}
+infallible!(SafeMax => {T: Ord + Clone} Option<T>);
+
+impl<T: Ord + Clone> Identity<SafeMin> for Option<T> {
+    fn identity() -> Option<T> {
+        None
+    }
+}
+
+impl<T: Ord + Clone> AbstractMagma<SafeMin> for Option<T> {
+    fn operate(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(std::cmp::min(a, b).clone()),
+            (a, None) => a.clone(),
+            (None, b) => b.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<SafeMin> for Option<T> {}
+impl<T: Ord + Clone> AbstractMonoid<SafeMin> for Option<T> {}
+infallible!(SafeMin => {T: Ord + Clone} Option<T>);
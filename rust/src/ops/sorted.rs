@@ -0,0 +1,84 @@
+use crate::ops::mode::merge_counts;
+use crate::ops::Sum;
+use crate::Lower;
+use crate::LowerInto;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use std::collections::BTreeMap;
+use std::iter;
+
+/// A partial aggregate of the values of the window in sorted order, which is a map
+/// from values to their number of occurrences, and which is lowered into a sorted
+/// `Vec` where each value is repeated by its count.
+///
+/// Inverted maps (under subtract-on-evict) have negative counts, which cancel out
+/// the counts of the evicted values.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct CollectSorted<T: Ord>(pub BTreeMap<T, i64>);
+
+impl<T: Ord> From<T> for CollectSorted<T> {
+    fn from(v: T) -> Self {
+        let mut counts = BTreeMap::new();
+        counts.insert(v, 1);
+        CollectSorted(counts)
+    }
+}
+
+impl<T: Ord + Clone> Lower<Sum> for CollectSorted<T> {
+    type Output = Vec<T>;
+    fn lower(&self) -> Vec<T> {
+        self.0
+            .iter()
+            .flat_map(|(v, count)| iter::repeat_n(v, (*count).max(0) as usize))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T: Ord + Clone> LowerInto<Sum> for CollectSorted<T> {
+    fn lower_into(&self, buf: &mut Vec<T>) {
+        buf.clear();
+        buf.extend(
+            self.0
+                .iter()
+                .flat_map(|(v, count)| iter::repeat_n(v, (*count).max(0) as usize))
+                .cloned(),
+        );
+    }
+}
+
+impl<T: Ord + Clone> Identity<Sum> for CollectSorted<T> {
+    fn identity() -> CollectSorted<T> {
+        CollectSorted(BTreeMap::new())
+    }
+}
+
+impl<T: Ord + Clone> AbstractMagma<Sum> for CollectSorted<T> {
+    fn operate(&self, other: &Self) -> Self {
+        CollectSorted(merge_counts(&self.0, &other.0))
+    }
+}
+
+impl<T: Ord + Clone> TwoSidedInverse<Sum> for CollectSorted<T> {
+    fn two_sided_inverse(&self) -> CollectSorted<T> {
+        CollectSorted(
+            self.0
+                .iter()
+                .map(|(v, count)| (v.clone(), -count))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Ord + Clone> AbstractSemigroup<Sum> for CollectSorted<T> {}
+impl<T: Ord + Clone> AbstractMonoid<Sum> for CollectSorted<T> {}
+infallible!(Sum => {T: Ord + Clone} CollectSorted<T>);
+impl<T: Ord + Clone> AbstractQuasigroup<Sum> for CollectSorted<T> {}
+impl<T: Ord + Clone> AbstractLoop<Sum> for CollectSorted<T> {}
+impl<T: Ord + Clone> AbstractGroup<Sum> for CollectSorted<T> {}
@@ -0,0 +1,59 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the maximum sum of a contiguous sub-range of the window,
+/// which is lowered into that sum. The empty sub-range is included, so the sum is
+/// never negative.
+///
+/// Two consecutive segments are concatenated by combining their total sums and
+/// best prefix, suffix, and overall sums.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct MaxSubarraySum {
+    pub total: i64,
+    pub prefix: i64,
+    pub suffix: i64,
+    pub best: i64,
+}
+
+impl From<i64> for MaxSubarraySum {
+    fn from(v: i64) -> Self {
+        let best = v.max(0);
+        MaxSubarraySum {
+            total: v,
+            prefix: best,
+            suffix: best,
+            best,
+        }
+    }
+}
+
+impl Lower<Concat> for MaxSubarraySum {
+    type Output = i64;
+    fn lower(&self) -> i64 {
+        self.best
+    }
+}
+
+impl Identity<Concat> for MaxSubarraySum {
+    fn identity() -> MaxSubarraySum {
+        MaxSubarraySum::default()
+    }
+}
+
+impl AbstractMagma<Concat> for MaxSubarraySum {
+    fn operate(&self, other: &Self) -> Self {
+        MaxSubarraySum {
+            total: self.total + other.total,
+            prefix: self.prefix.max(self.total + other.prefix),
+            suffix: other.suffix.max(other.total + self.suffix),
+            best: self.best.max(other.best).max(self.suffix + other.prefix),
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for MaxSubarraySum {}
+impl AbstractMonoid<Concat> for MaxSubarraySum {}
@@ -0,0 +1,52 @@
+use crate::ops::Sum;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// Implements `Sum` for primitive numbers, whose identity is zero.
+macro_rules! impl_sum {
+    { $($ty:ty),* } => {
+        $(
+            impl Identity<Sum> for $ty {
+                fn identity() -> $ty {
+                    0 as $ty
+                }
+            }
+
+            impl AbstractMagma<Sum> for $ty {
+                fn operate(&self, other: &Self) -> Self {
+                    *self + *other
+                }
+            }
+
+            impl AbstractSemigroup<Sum> for $ty {}
+            impl AbstractMonoid<Sum> for $ty {}
+            infallible!(Sum => $ty);
+        )*
+    }
+}
+
+/// Implements the inverse of `Sum` for signed primitive numbers.
+macro_rules! impl_sum_inverse {
+    { $($ty:ty),* } => {
+        $(
+            impl TwoSidedInverse<Sum> for $ty {
+                fn two_sided_inverse(&self) -> $ty {
+                    -*self
+                }
+            }
+
+            impl AbstractQuasigroup<Sum> for $ty {}
+            impl AbstractLoop<Sum> for $ty {}
+            impl AbstractGroup<Sum> for $ty {}
+        )*
+    }
+}
+
+impl_sum! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 }
+impl_sum_inverse! { i8, i16, i32, i64, i128, isize, f32, f64 }
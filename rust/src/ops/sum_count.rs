@@ -0,0 +1,55 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// The sum and count of `i64` values, which is lowered into the pair `(sum, count)`.
+///
+/// Counts are added with wrapping arithmetic, so that they can be inverted
+/// (under subtract-on-evict) without becoming signed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct SumCount(pub i64, pub usize);
+
+impl From<i64> for SumCount {
+    fn from(v: i64) -> Self {
+        SumCount(v, 1)
+    }
+}
+
+impl Lower<Sum> for SumCount {
+    type Output = (i64, usize);
+    fn lower(&self) -> (i64, usize) {
+        (self.0, self.1)
+    }
+}
+
+impl Identity<Sum> for SumCount {
+    fn identity() -> SumCount {
+        SumCount(0, 0)
+    }
+}
+
+impl AbstractMagma<Sum> for SumCount {
+    fn operate(&self, other: &Self) -> Self {
+        SumCount(self.0 + other.0, self.1.wrapping_add(other.1))
+    }
+}
+
+impl TwoSidedInverse<Sum> for SumCount {
+    fn two_sided_inverse(&self) -> SumCount {
+        SumCount(-self.0, self.1.wrapping_neg())
+    }
+}
+
+impl AbstractSemigroup<Sum> for SumCount {}
+impl AbstractMonoid<Sum> for SumCount {}
+infallible!(Sum => SumCount);
+impl AbstractQuasigroup<Sum> for SumCount {}
+impl AbstractLoop<Sum> for SumCount {}
+impl AbstractGroup<Sum> for SumCount {}
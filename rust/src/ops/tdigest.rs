@@ -0,0 +1,161 @@
+use crate::ops::Sum;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use std::f64::consts::PI;
+
+/// The default compression, which bounds the number of centroids to roughly 100.
+const DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A t-digest sketch which estimates quantiles of a distribution, where the
+/// estimates are most accurate at the tails.
+///
+/// Summing two digests merges their centroids, which is associative up to the
+/// approximation error of the sketch. The compression of the sum is the larger
+/// compression of the two digests.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TDigest {
+    centroids: Vec<Centroid>,
+    compression: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Returns an empty digest with a `compression` which trades accuracy for size.
+    pub fn new(compression: f64) -> Self {
+        Self {
+            centroids: Vec::new(),
+            compression,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+    /// Returns a digest of a single value with the default compression.
+    pub fn with_value(value: f64) -> Self {
+        Self::with_value_and_compression(value, DEFAULT_COMPRESSION)
+    }
+    /// Returns a digest of a single value with a `compression` which trades accuracy for size.
+    pub fn with_value_and_compression(value: f64, compression: f64) -> Self {
+        Self {
+            centroids: vec![Centroid {
+                mean: value,
+                weight: 1.0,
+            }],
+            compression,
+            min: value,
+            max: value,
+        }
+    }
+    /// Returns the number of values in the digest.
+    pub fn count(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum()
+    }
+    /// Returns an estimate of the `q`-quantile, where `0 <= q <= 1`,
+    /// or `None` if the digest is empty.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let first = self.centroids.first()?;
+        let last = self.centroids.last()?;
+        // The extremes are tracked exactly, so they are returned without interpolation
+        if q <= 0.0 {
+            return Some(self.min);
+        } else if q >= 1.0 {
+            return Some(self.max);
+        }
+        let target = q * self.count();
+        // Interpolates between the centers of neighbouring centroids
+        let mut prev = (0.0, self.min);
+        let mut cumulative = 0.0;
+        for c in &self.centroids {
+            let center = cumulative + c.weight / 2.0;
+            if target < center {
+                let (prev_center, prev_mean) = prev;
+                if center == prev_center {
+                    return Some(c.mean);
+                }
+                let t = (target - prev_center) / (center - prev_center);
+                return Some(prev_mean + t * (c.mean - prev_mean));
+            }
+            prev = (center, c.mean);
+            cumulative += c.weight;
+        }
+        let (prev_center, prev_mean) = prev;
+        if cumulative == prev_center || first.mean == last.mean {
+            return Some(prev_mean);
+        }
+        let t = (target - prev_center) / (cumulative - prev_center);
+        Some(prev_mean + t * (self.max - prev_mean))
+    }
+    /// The scale function, which maps a quantile to a centroid index.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+    /// The inverse of the scale function.
+    fn k_inv(&self, k: f64) -> f64 {
+        let angle = (k * 2.0 * PI / self.compression).min(PI / 2.0);
+        (angle.sin() + 1.0) / 2.0
+    }
+    /// Merges neighbouring centroids as long as they fit within one unit of the scale function.
+    fn compress(&mut self) {
+        if self.centroids.len() <= 1 {
+            return;
+        }
+        self.centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total = self.count();
+        let mut merged = Vec::with_capacity(self.centroids.len());
+        let mut iter = self.centroids.iter();
+        let mut current = *iter.next().unwrap();
+        let mut q_left = 0.0;
+        let mut q_limit = self.k_inv(self.k(q_left) + 1.0);
+        for c in iter {
+            let q = q_left + (current.weight + c.weight) / total;
+            if q <= q_limit {
+                let weight = current.weight + c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / weight;
+                current.weight = weight;
+            } else {
+                q_left += current.weight / total;
+                q_limit = self.k_inv(self.k(q_left) + 1.0);
+                merged.push(current);
+                current = *c;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+}
+
+impl Identity<Sum> for TDigest {
+    fn identity() -> TDigest {
+        TDigest::new(0.0)
+    }
+}
+
+impl AbstractMagma<Sum> for TDigest {
+    fn operate(&self, other: &Self) -> Self {
+        let mut digest = TDigest {
+            centroids: self
+                .centroids
+                .iter()
+                .chain(other.centroids.iter())
+                .cloned()
+                .collect(),
+            compression: self.compression.max(other.compression),
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        };
+        digest.compress();
+        digest
+    }
+}
+
+impl AbstractSemigroup<Sum> for TDigest {}
+impl AbstractMonoid<Sum> for TDigest {}
+infallible!(Sum => TDigest);
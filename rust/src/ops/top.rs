@@ -0,0 +1,89 @@
+use crate::ops::Key;
+use crate::Lower;
+use crate::LowerInto;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use std::marker::PhantomData;
+
+/// Binary operator for selecting the `K` values with the largest keys under the key
+/// function `F`. On ties, newer values are ranked before older values, like `MaxBy`.
+/// Has the following properties:
+/// * Associativity
+pub struct TopKBy<F, const K: usize>(PhantomData<F>);
+
+impl<F, const K: usize> Clone for TopKBy<F, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F, const K: usize> Copy for TopKBy<F, K> {}
+
+impl<F, const K: usize> Operator for TopKBy<F, K> {
+    fn operator_token() -> TopKBy<F, K> {
+        TopKBy(PhantomData)
+    }
+}
+
+/// At most `K` values sorted by descending key, which is lowered into the values.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Top<Value>(pub Vec<Value>);
+
+impl<Value> From<Value> for Top<Value> {
+    fn from(v: Value) -> Self {
+        Top(vec![v])
+    }
+}
+
+impl<Value: Clone + PartialEq, F: Key<Value>, const K: usize> Lower<TopKBy<F, K>> for Top<Value> {
+    type Output = Vec<Value>;
+    fn lower(&self) -> Vec<Value> {
+        self.0.clone()
+    }
+}
+
+impl<Value: Clone + PartialEq, F: Key<Value>, const K: usize> LowerInto<TopKBy<F, K>>
+    for Top<Value>
+{
+    fn lower_into(&self, buf: &mut Vec<Value>) {
+        buf.clear();
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl<Value, F, const K: usize> Identity<TopKBy<F, K>> for Top<Value> {
+    fn identity() -> Top<Value> {
+        Top(Vec::new())
+    }
+}
+
+impl<Value: Clone, F: Key<Value>, const K: usize> AbstractMagma<TopKBy<F, K>> for Top<Value> {
+    fn operate(&self, other: &Self) -> Self {
+        let mut merged = Vec::with_capacity(K.min(self.0.len() + other.0.len()));
+        let mut older = self.0.iter().peekable();
+        let mut newer = other.0.iter().peekable();
+        while merged.len() < K {
+            let next = match (older.peek(), newer.peek()) {
+                (Some(a), Some(b)) if F::key(a) > F::key(b) => older.next(),
+                (Some(_), Some(_)) | (None, Some(_)) => newer.next(),
+                (Some(_), None) => older.next(),
+                (None, None) => break,
+            };
+            merged.extend(next.cloned());
+        }
+        Top(merged)
+    }
+}
+
+impl<Value: Clone + PartialEq, F: Key<Value>, const K: usize> AbstractSemigroup<TopKBy<F, K>>
+    for Top<Value>
+{
+}
+impl<Value: Clone + PartialEq, F: Key<Value>, const K: usize> AbstractMonoid<TopKBy<F, K>>
+    for Top<Value>
+{
+}
+infallible!(TopKBy<F, K> => {Value: Clone + PartialEq, F: Key<Value>, const K: usize} Top<Value>);
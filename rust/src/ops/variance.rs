@@ -0,0 +1,143 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A partial aggregate of the population variance, which is the count, mean, and sum
+/// of squared deviations from the mean (`m2`), and which is lowered into an `f64`.
+/// The variance of an empty window is `NaN`.
+///
+/// Partials are summed with the parallel algorithm of Chan et al., which is
+/// numerically stable but cannot be inverted. Use `InvertibleVariance` to aggregate
+/// the variance under subtract-on-evict.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Variance {
+    pub count: f64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+/// A partial aggregate of the population variance like `Variance`, which can also be
+/// inverted to remove the contribution of an evicted value in `O(1)`.
+///
+/// Summing an inverted partial rolls back the count, mean, and `m2` like a
+/// Welford update in reverse, so the result drifts from `Variance` by rounding
+/// error over many evictions.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct InvertibleVariance {
+    pub count: f64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+/// Merges the moments `(count, mean, m2)` of two partials, where counts may be negative.
+fn merge(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (count_a, mean_a, m2_a) = a;
+    let (count_b, mean_b, m2_b) = b;
+    let count = count_a + count_b;
+    if count_b == 0.0 {
+        a
+    } else if count_a == 0.0 {
+        b
+    } else if count == 0.0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        let delta = mean_b - mean_a;
+        let mean = mean_a + delta * count_b / count;
+        let m2 = m2_a + m2_b + delta * delta * count_a * count_b / count;
+        (count, mean, m2)
+    }
+}
+
+impl From<f64> for Variance {
+    fn from(v: f64) -> Self {
+        Variance {
+            count: 1.0,
+            mean: v,
+            m2: 0.0,
+        }
+    }
+}
+
+impl Lower<Sum> for Variance {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        self.m2 / self.count
+    }
+}
+
+impl Identity<Sum> for Variance {
+    fn identity() -> Variance {
+        Variance::default()
+    }
+}
+
+impl AbstractMagma<Sum> for Variance {
+    fn operate(&self, other: &Self) -> Self {
+        let (count, mean, m2) = merge(
+            (self.count, self.mean, self.m2),
+            (other.count, other.mean, other.m2),
+        );
+        Variance { count, mean, m2 }
+    }
+}
+
+impl AbstractSemigroup<Sum> for Variance {}
+impl AbstractMonoid<Sum> for Variance {}
+infallible!(Sum => Variance);
+
+impl From<f64> for InvertibleVariance {
+    fn from(v: f64) -> Self {
+        InvertibleVariance {
+            count: 1.0,
+            mean: v,
+            m2: 0.0,
+        }
+    }
+}
+
+impl Lower<Sum> for InvertibleVariance {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        self.m2 / self.count
+    }
+}
+
+impl Identity<Sum> for InvertibleVariance {
+    fn identity() -> InvertibleVariance {
+        InvertibleVariance::default()
+    }
+}
+
+impl AbstractMagma<Sum> for InvertibleVariance {
+    fn operate(&self, other: &Self) -> Self {
+        let (count, mean, m2) = merge(
+            (self.count, self.mean, self.m2),
+            (other.count, other.mean, other.m2),
+        );
+        InvertibleVariance { count, mean, m2 }
+    }
+}
+
+impl TwoSidedInverse<Sum> for InvertibleVariance {
+    fn two_sided_inverse(&self) -> InvertibleVariance {
+        InvertibleVariance {
+            count: -self.count,
+            mean: self.mean,
+            m2: -self.m2,
+        }
+    }
+}
+
+impl AbstractSemigroup<Sum> for InvertibleVariance {}
+impl AbstractMonoid<Sum> for InvertibleVariance {}
+infallible!(Sum => InvertibleVariance);
+impl AbstractQuasigroup<Sum> for InvertibleVariance {}
+impl AbstractLoop<Sum> for InvertibleVariance {}
+impl AbstractGroup<Sum> for InvertibleVariance {}
@@ -0,0 +1,59 @@
+use crate::ops::Concat;
+use crate::Lower;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+
+/// A partial aggregate of the total variation of the window, i.e., the sum of absolute
+/// differences `|x_i - x_(i-1)|` between consecutive values, which is lowered into
+/// that sum.
+///
+/// Two consecutive segments are concatenated by adding their total variations and the
+/// absolute difference between the last value of the older segment and the first value
+/// of the newer segment.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct TotalVariation {
+    /// The first and last value of the segment, or `None` if it is empty.
+    pub ends: Option<(i64, i64)>,
+    pub total: i64,
+}
+
+impl From<i64> for TotalVariation {
+    fn from(v: i64) -> Self {
+        TotalVariation {
+            ends: Some((v, v)),
+            total: 0,
+        }
+    }
+}
+
+impl Lower<Concat> for TotalVariation {
+    type Output = i64;
+    fn lower(&self) -> i64 {
+        self.total
+    }
+}
+
+impl Identity<Concat> for TotalVariation {
+    fn identity() -> TotalVariation {
+        TotalVariation::default()
+    }
+}
+
+impl AbstractMagma<Concat> for TotalVariation {
+    fn operate(&self, other: &Self) -> Self {
+        match (self.ends, other.ends) {
+            (Some((first, last)), Some((next, newest))) => TotalVariation {
+                ends: Some((first, newest)),
+                total: self.total + other.total + (next - last).abs(),
+            },
+            (_, None) => *self,
+            (None, _) => *other,
+        }
+    }
+}
+
+impl AbstractSemigroup<Concat> for TotalVariation {}
+impl AbstractMonoid<Concat> for TotalVariation {}
+infallible!(Concat => TotalVariation);
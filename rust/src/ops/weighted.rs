@@ -0,0 +1,53 @@
+use crate::ops::Sum;
+use crate::Lower;
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+
+/// A sum of values which are scaled by their weights, where each element is
+/// pushed as a pair `(value, weight)`, and which is lowered into an `f64`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct WeightedSum(pub f64);
+
+impl From<(f64, f64)> for WeightedSum {
+    fn from((value, weight): (f64, f64)) -> Self {
+        WeightedSum(value * weight)
+    }
+}
+
+impl Lower<Sum> for WeightedSum {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Identity<Sum> for WeightedSum {
+    fn identity() -> WeightedSum {
+        WeightedSum(0.0)
+    }
+}
+
+impl AbstractMagma<Sum> for WeightedSum {
+    fn operate(&self, other: &Self) -> Self {
+        WeightedSum(self.0 + other.0)
+    }
+}
+
+impl TwoSidedInverse<Sum> for WeightedSum {
+    fn two_sided_inverse(&self) -> WeightedSum {
+        WeightedSum(-self.0)
+    }
+}
+
+impl AbstractSemigroup<Sum> for WeightedSum {}
+impl AbstractMonoid<Sum> for WeightedSum {}
+infallible!(Sum => WeightedSum);
+impl AbstractQuasigroup<Sum> for WeightedSum {}
+impl AbstractLoop<Sum> for WeightedSum {}
+impl AbstractGroup<Sum> for WeightedSum {}
@@ -0,0 +1,59 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+
+/// A window adaptor which applies a function `f` to the aggregate of every query,
+/// e.g., mapping `|s| s as f64 / 100.0` over a sum of cents queries dollars.
+/// The values inside the window are aggregated unchanged.
+#[derive(Clone)]
+pub struct OutputMap<Window, F> {
+    window: Window,
+    f: F,
+}
+
+impl<Window, F> OutputMap<Window, F> {
+    /// Returns an adaptor which maps the aggregates of `window` through `f`.
+    pub fn new(window: Window, f: F) -> Self {
+        Self { window, f }
+    }
+    /// Inserts a value at the back of the window.
+    pub fn push<Value, BinOp>(&mut self, v: Value)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.push(v);
+    }
+    /// Removes a value at the front of the window (if any).
+    pub fn pop<Value, BinOp>(&mut self) -> Option<Value>
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.pop()
+    }
+    /// Combines the values in fifo order and returns the mapped result.
+    pub fn query<Value, BinOp, R>(&self) -> R
+    where
+        Window: FifoWindow<Value, BinOp>,
+        F: Fn(Value) -> R,
+        BinOp: Operator,
+    {
+        (self.f)(self.window.query())
+    }
+    /// Returns the number of elements inside the window.
+    pub fn len<Value, BinOp>(&self) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.len()
+    }
+    /// Returns true if the window contains no elements.
+    pub fn is_empty<Value, BinOp>(&self) -> bool
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.is_empty()
+    }
+}
@@ -1,14 +1,17 @@
-use alga::general::AbstractMonoid;
+use crate::Monoid;
 use alga::general::Operator;
 use fxhash::FxHashSet as HashSet;
 use std::marker::PhantomData;
+use std::ops::Range;
 
+#[allow(clippy::upper_case_acronyms)]
 pub(crate) trait FAT<Value, BinOp>: Clone
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     /// Creates a new FAT from a batch of values
+    #[allow(dead_code)]
     fn new(batch: &[Value]) -> Self;
 
     /// Creates a new FAT with uninitialized values
@@ -35,12 +38,15 @@ where
 
     /// Aggregates a suffix of nodes in the FAT and returns the result
     fn suffix(&self, i: usize) -> Value;
+
+    /// Aggregates the leaves in `range` in order and returns the result
+    fn range(&self, range: Range<usize>) -> Value;
 }
 
 #[derive(Clone)]
 pub(crate) struct FlatFAT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     /// A flat binary tree, indexed as:
@@ -58,7 +64,7 @@ where
 
 impl<Value, BinOp> FlatFAT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     /// Returns all leaf nodes of the tree
@@ -66,6 +72,15 @@ where
     pub(crate) fn leaves(&self) -> &[Value] {
         &self.tree[self.leaf(0)..]
     }
+    /// Returns true if every inner node aggregates its children.
+    #[cfg(feature = "inspect")]
+    pub(crate) fn is_consistent(&self) -> bool
+    where
+        Value: PartialEq,
+    {
+        (0..self.capacity - 1)
+            .all(|i| self.tree[i] == self.tree[self.left(i)].operate(&self.tree[self.right(i)]))
+    }
     /// Returns the index of the root node
     #[inline(always)]
     fn root(&self) -> usize {
@@ -95,7 +110,7 @@ where
 
 impl<Value, BinOp> FAT<Value, BinOp> for FlatFAT<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     fn new(values: &[Value]) -> Self {
@@ -193,4 +208,26 @@ where
         }
         agg
     }
+
+    fn range(&self, range: Range<usize>) -> Value {
+        // Walks up from both ends of the range using one-based node numbering,
+        // where the children of node `n` are `2n` and `2n+1`.
+        let mut left_agg = Value::identity();
+        let mut right_agg = Value::identity();
+        let mut l = self.leaf(range.start) + 1;
+        let mut r = self.leaf(range.end) + 1;
+        while l < r {
+            if l % 2 == 1 {
+                left_agg = left_agg.operate(&self.tree[l - 1]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right_agg = self.tree[r - 1].operate(&right_agg);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        left_agg.operate(&right_agg)
+    }
 }
@@ -1,14 +1,23 @@
 pub(crate) mod flat_fat;
 
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
 use crate::reactive::flat_fat::{FlatFAT, FAT};
 use crate::FifoWindow;
-use alga::general::AbstractMonoid;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
+use crate::SubWindow;
 use alga::general::Operator;
+use std::ops::Range;
 
 #[derive(Clone)]
 pub struct Reactive<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     fat: FlatFAT<Value, BinOp>,
@@ -19,9 +28,19 @@ where
 
 impl<Value, BinOp> Reactive<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
+    /// Returns the time complexity of the operations of the window. Pushes and pops
+    /// update the path from a leaf to the root, and resize the tree when it becomes
+    /// full or sparse.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::amortized(Order::Logarithmic, Order::Linear),
+            pop: Bounds::amortized(Order::Logarithmic, Order::Linear),
+            query: Bounds::exact(Order::Logarithmic),
+        }
+    }
     /// Returns a Reactive Aggregator with a pre-allocated `capacity`
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
@@ -31,6 +50,48 @@ where
             back: 0,
         }
     }
+    /// Returns an iterator over the values in fifo order.
+    fn values(&self) -> impl Iterator<Item = &Value> {
+        let leaves = self.fat.leaves();
+        leaves[self.front..]
+            .iter()
+            .chain(leaves[..self.front].iter())
+            .take(self.size)
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The leaves
+    /// are copied in fifo order into a fresh segment tree, which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Checks that the window fits within the leaves of the tree, that leaves outside the
+    /// window hold the identity, and that every inner node aggregates its children.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        let capacity = self.fat.capacity;
+        if self.size >= capacity || (self.front + self.size) % capacity != self.back {
+            return Err(InvariantError("window does not fit within the leaves"));
+        }
+        let leaves = self.fat.leaves();
+        if (self.size..capacity).any(|i| leaves[(self.front + i) % capacity] != Value::identity()) {
+            return Err(InvariantError(
+                "leaf outside the window is not the identity",
+            ));
+        }
+        if !self.fat.is_consistent() {
+            return Err(InvariantError("inner node does not aggregate its children"));
+        }
+        Ok(())
+    }
     fn inverted(&self) -> bool {
         self.front >= self.back
     }
@@ -55,7 +116,7 @@ where
 
 impl<Value, BinOp> FifoWindow<Value, BinOp> for Reactive<Value, BinOp>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
     fn new() -> Self {
@@ -89,6 +150,19 @@ where
             None
         }
     }
+    /// Replaces the front leaf with the identity and the back leaf with `v` in a single
+    /// batched update, without resizing the tree.
+    fn slide(&mut self, v: Value) -> Value {
+        if self.size == 0 {
+            return v;
+        }
+        let val = self.fat.get(self.front).cloned().unwrap();
+        self.fat
+            .update(vec![(self.front, Value::identity()), (self.back, v)]);
+        self.front = (self.front + 1) % self.fat.capacity;
+        self.back = (self.back + 1) % self.fat.capacity;
+        val
+    }
     fn query(&self) -> Value {
         if self.front > self.back {
             self.fat
@@ -105,3 +179,36 @@ where
         self.size == 0
     }
 }
+
+impl<Value, BinOp> SubWindow<usize, Value> for Reactive<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Aggregates the values at positions `range`, counted from the front of the window.
+    fn range_query(&self, range: Range<usize>) -> Value {
+        let end = std::cmp::min(range.end, self.size);
+        if range.start >= end {
+            return Value::identity();
+        }
+        let start = self.front + range.start;
+        let end = self.front + end;
+        let capacity = self.fat.capacity;
+        if end <= capacity {
+            self.fat.range(start..end)
+        } else if start >= capacity {
+            self.fat.range(start - capacity..end - capacity)
+        } else {
+            self.fat
+                .range(start..capacity)
+                .operate(&self.fat.range(0..end - capacity))
+        }
+    }
+}
+
+impl_eq_by_values! {
+    Reactive<Value, BinOp>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+}
@@ -1,40 +1,246 @@
+use crate::alloc::Allocator;
+use crate::alloc::Global;
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
+use crate::Absorbing;
 use crate::FifoWindow;
-use alga::general::AbstractMonoid;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
+use crate::SubWindow;
 use alga::general::Operator;
+use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::marker::PhantomData;
+use std::ops::Range;
 
-#[derive(Clone)]
-pub struct ReCalc<Value, BinOp>
+/// A window which recalculates its aggregate from scratch when it is queried after a pop.
+///
+/// Queries cache the aggregate through a `RefCell`, so the window is `Send` if its
+/// values are, but never `Sync`, i.e., it cannot be queried through a shared reference
+/// from several threads. Wrap it in a `Mutex` to share it between threads.
+pub struct ReCalc<Value, BinOp, A = Global>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
+    A: Allocator + Clone,
 {
-    stack: VecDeque<Value>,
-    op: PhantomData<BinOp>,
+    stack: alloc_type!(VecDeque<Value, A>),
+    /// The result of the last query, if the window has not changed since it was computed
+    cache: RefCell<Option<Value>>,
+    /// Whether the aggregate is recalculated on the next query instead of on every mutation
+    lazy: bool,
+    /// Returns true if an aggregate is absorbing, if recalculations skip the values after it
+    absorbing: Option<fn(&Value) -> bool>,
+    op: PhantomData<(BinOp, A)>,
 }
 
-impl<Value, BinOp> FifoWindow<Value, BinOp> for ReCalc<Value, BinOp>
+impl<Value, BinOp, A> Clone for ReCalc<Value, BinOp, A>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
+    A: Allocator + Clone,
 {
-    fn new() -> Self {
+    fn clone(&self) -> Self {
         Self {
-            stack: VecDeque::new(),
+            stack: self.stack.clone(),
+            cache: self.cache.clone(),
+            lazy: self.lazy,
+            absorbing: self.absorbing,
+            op: PhantomData,
+        }
+    }
+}
+
+impl<Value, BinOp, A> ReCalc<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    /// Returns the time complexity of the operations of the window. Queries recalculate
+    /// the aggregate from scratch after any mutation.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::exact(Order::Constant),
+            pop: Bounds::exact(Order::Constant),
+            query: Bounds::exact(Order::Linear),
+        }
+    }
+    /// Returns an empty window whose values are allocated by `alloc`.
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            stack: alloc_new!(VecDeque, alloc),
+            cache: RefCell::new(None),
+            lazy: true,
+            absorbing: None,
+            op: PhantomData,
+        }
+    }
+    /// Returns an iterator over the values in fifo order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        self.stack.iter()
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The values
+    /// are copied into a segment tree, which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Combines all values except the newest one in fifo order and returns the result.
+    pub fn query_excluding_newest(&self) -> Value {
+        let n = self.stack.len().saturating_sub(1);
+        self.stack
+            .range(..n)
+            .fold(Value::identity(), |acc, elem| acc.operate(elem))
+    }
+    /// Returns a builder of a window, which can configure how the aggregate is cached.
+    pub fn builder() -> ReCalcBuilder<Value, BinOp, A> {
+        ReCalcBuilder {
+            lazy: true,
+            absorbing: None,
             op: PhantomData,
         }
     }
+    /// Combines the cached aggregate with a pushed value if the window is eager, and
+    /// forgets the cached aggregate otherwise.
+    fn fold(&mut self, v: &Value) {
+        let cache = self.cache.get_mut();
+        *cache = match cache.take() {
+            Some(agg) if !self.lazy => Some(agg.operate(v)),
+            _ => None,
+        };
+    }
+    /// Forgets the cached aggregate, and recalculates it if the window is eager.
+    fn invalidate(&mut self) {
+        let agg = if self.lazy {
+            None
+        } else {
+            Some(self.aggregate())
+        };
+        *self.cache.get_mut() = agg;
+    }
+    /// Combines the values in fifo order from scratch. If the window was built with
+    /// `absorbing`, it stops combining as soon as the aggregate is absorbing, which skips
+    /// the remaining values.
+    fn aggregate(&self) -> Value {
+        let mut agg = Value::identity();
+        for v in self.stack.iter() {
+            if matches!(self.absorbing, Some(is_absorbing) if is_absorbing(&agg)) {
+                break;
+            }
+            agg = agg.operate(v);
+        }
+        agg
+    }
+    /// Checks that the cached aggregate (if any) matches the values, and that an eager
+    /// window always has a cached aggregate.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        match &*self.cache.borrow() {
+            Some(agg) if *agg != self.aggregate() => Err(InvariantError("stale cached aggregate")),
+            None if !self.lazy => Err(InvariantError("eager window without cached aggregate")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A builder of a `ReCalc` window.
+pub struct ReCalcBuilder<Value, BinOp, A = Global> {
+    lazy: bool,
+    absorbing: Option<fn(&Value) -> bool>,
+    op: PhantomData<(Value, BinOp, A)>,
+}
+
+impl<Value, BinOp, A> ReCalcBuilder<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    /// Sets whether the aggregate is recalculated lazily (the default), i.e., once on the
+    /// first query after any number of mutations, or eagerly, i.e., pushed values are
+    /// combined with the cached aggregate and pops recalculate it, so that queries
+    /// never have to.
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+    /// Makes recalculations stop combining values as soon as the aggregate is absorbing,
+    /// which skips the remaining values, e.g., once an `Any` aggregate becomes true.
+    pub fn absorbing(mut self) -> Self
+    where
+        Value: Absorbing<BinOp>,
+    {
+        self.absorbing = Some(Value::is_absorbing);
+        self
+    }
+    /// Returns an empty window whose values are allocated by `alloc`.
+    pub fn build_in(self, alloc: A) -> ReCalc<Value, BinOp, A> {
+        let mut window = ReCalc::new_in(alloc);
+        window.lazy = self.lazy;
+        window.absorbing = self.absorbing;
+        window.invalidate();
+        window
+    }
+    /// Returns an empty window.
+    pub fn build(self) -> ReCalc<Value, BinOp, A>
+    where
+        A: Default,
+    {
+        self.build_in(A::default())
+    }
+}
+
+impl<Value, BinOp, A> FifoWindow<Value, BinOp> for ReCalc<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone + Default,
+{
+    fn new() -> Self {
+        Self::new_in(A::default())
+    }
     fn push(&mut self, v: Value) {
+        self.fold(&v);
+        self.stack.push_back(v);
+    }
+    /// Appends the whole slice at once, and combines it with the cached aggregate if
+    /// the window is eager.
+    fn push_all(&mut self, slice: &[Value]) {
+        slice.iter().for_each(|v| self.fold(v));
+        self.stack.extend(slice.iter().cloned());
+    }
+    /// Combines the cached aggregate (if any) with the value instead of recalculating it.
+    fn push_query(&mut self, v: Value) -> Value {
+        let agg = self.cache.get_mut().take().map(|agg| agg.operate(&v));
         self.stack.push_back(v);
+        let agg = agg.unwrap_or_else(|| self.aggregate());
+        *self.cache.get_mut() = Some(agg.clone());
+        agg
     }
     fn pop(&mut self) -> Option<Value> {
-        self.stack.pop_front()
+        let v = self.stack.pop_front();
+        self.invalidate();
+        v
     }
     fn query(&self) -> Value {
-        self.stack
-            .iter()
-            .fold(Value::identity(), |acc, elem| acc.operate(&elem))
+        self.cache
+            .borrow_mut()
+            .get_or_insert_with(|| self.aggregate())
+            .clone()
     }
     fn len(&self) -> usize {
         self.stack.len()
@@ -43,3 +249,54 @@ where
         self.stack.is_empty()
     }
 }
+
+impl<Value, BinOp, A> SubWindow<usize, Value> for ReCalc<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    /// Aggregates the values at positions `range`, counted from the front of the window.
+    fn range_query(&self, range: Range<usize>) -> Value {
+        let end = std::cmp::min(range.end, self.stack.len());
+        if range.start >= end {
+            return Value::identity();
+        }
+        self.stack
+            .range(range.start..end)
+            .fold(Value::identity(), |acc, elem| acc.operate(elem))
+    }
+    /// Ranges which start at the same position share a single scan over the window.
+    fn query_ranges(&self, ranges: &[Range<usize>]) -> Vec<Value> {
+        let mut order = (0..ranges.len()).collect::<Vec<_>>();
+        order.sort_by_key(|i| (ranges[*i].start, ranges[*i].end));
+        let mut aggs = vec![Value::identity(); ranges.len()];
+        let mut scan: Option<(usize, usize, Value)> = None;
+        for i in order {
+            let start = ranges[i].start;
+            let end = std::cmp::min(ranges[i].end, self.stack.len());
+            if start >= end {
+                continue;
+            }
+            let (mut pos, mut agg) = match scan.take() {
+                Some((scan_start, scan_end, agg)) if scan_start == start => (scan_end, agg),
+                _ => (start, Value::identity()),
+            };
+            for v in self.stack.range(pos..end) {
+                agg = agg.operate(v);
+            }
+            pos = std::cmp::max(pos, end);
+            aggs[i] = agg.clone();
+            scan = Some((start, pos, agg));
+        }
+        aggs
+    }
+}
+
+impl_eq_by_values! {
+    ReCalc<Value, BinOp, A>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+        A: Allocator + Clone,
+}
@@ -0,0 +1,44 @@
+/// A FIFO algorithm of this crate, as recommended by `recommend`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlgorithmKind {
+    /// `recalc::ReCalc`
+    ReCalc,
+    /// `two_stacks::TwoStacks`
+    TwoStacks,
+    /// `reactive::Reactive`
+    Reactive,
+    /// `adaptive::Adaptive`
+    Adaptive,
+}
+
+/// Windows of at most this many elements are cheapest to recalculate from scratch.
+const TINY_WINDOW: usize = 32;
+
+/// Windows of at least this many elements make the `O(n)` flips of Two-Stacks
+/// noticeable as latency spikes.
+const LARGE_WINDOW: usize = 1 << 16;
+
+/// Returns the algorithm which is expected to be cheapest for a window which holds
+/// around `window_size_hint` elements (or `0` if unknown), where `pop_rate` is the
+/// number of pops per push, e.g., `1.0` for a window which slides at a constant size.
+///
+/// * Tiny windows are recalculated from scratch, which has the lowest constant factor.
+/// * Large windows which are popped about as often as they are pushed flip the stacks
+///   of Two-Stacks regularly, so the worst-case `O(log n)` Reactive is used instead.
+/// * Windows of unknown size are served by Adaptive, which migrates between
+///   ReCalc and Two-Stacks as the window grows and shrinks.
+/// * Otherwise, Two-Stacks gives the best amortized throughput.
+///
+/// Note that Subtract-On-Evict is cheaper than all of the above, but only applies to
+/// invertible operators.
+pub fn recommend(window_size_hint: usize, pop_rate: f64) -> AlgorithmKind {
+    if window_size_hint == 0 {
+        AlgorithmKind::Adaptive
+    } else if window_size_hint <= TINY_WINDOW {
+        AlgorithmKind::ReCalc
+    } else if window_size_hint >= LARGE_WINDOW && pop_rate >= 0.5 {
+        AlgorithmKind::Reactive
+    } else {
+        AlgorithmKind::TwoStacks
+    }
+}
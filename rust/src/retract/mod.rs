@@ -0,0 +1,100 @@
+use crate::segtree::SegTree;
+use crate::FifoWindow;
+use crate::Monoid;
+use alga::general::Operator;
+use std::collections::VecDeque;
+
+/// An opaque handle to a value which was inserted into a `Retractable` window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Handle(usize);
+
+/// A window whose values can be retracted in any order by the handle returned when
+/// inserting them, in addition to being popped in fifo order.
+///
+/// Values are kept in a segment tree, where a retracted value is replaced by the
+/// identity in `O(log n)`. Its slot is freed once it reaches the front of the window.
+#[derive(Clone)]
+pub struct Retractable<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    tree: SegTree<Value, BinOp>,
+    /// Whether the value in each slot of the tree has not been retracted
+    live: VecDeque<bool>,
+    /// Sequence number of the slot at the front of the tree
+    front: usize,
+    len: usize,
+}
+
+impl<Value, BinOp> Retractable<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Inserts a value at the back of the window, and returns a handle to retract it.
+    pub fn insert(&mut self, v: Value) -> Handle {
+        self.tree.push(v);
+        self.live.push_back(true);
+        self.len += 1;
+        Handle(self.front + self.live.len() - 1)
+    }
+    /// Removes the value of `handle` from the window, and returns it, or `None` if it
+    /// was already removed.
+    pub fn remove(&mut self, handle: Handle) -> Option<Value> {
+        let pos = handle.0.checked_sub(self.front)?;
+        let live = self.live.get_mut(pos)?;
+        if !*live {
+            return None;
+        }
+        *live = false;
+        self.len -= 1;
+        let v = self.tree.replace(pos, Value::identity());
+        self.trim();
+        Some(v)
+    }
+    /// Frees the slots of retracted values at the front of the window.
+    fn trim(&mut self) {
+        while self.live.front() == Some(&false) {
+            self.live.pop_front();
+            self.tree.pop();
+            self.front += 1;
+        }
+    }
+}
+
+impl<Value, BinOp> FifoWindow<Value, BinOp> for Retractable<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            tree: SegTree::new(),
+            live: VecDeque::new(),
+            front: 0,
+            len: 0,
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.insert(v);
+    }
+    /// Removes the oldest value which has not been retracted (if any).
+    fn pop(&mut self) -> Option<Value> {
+        self.live.pop_front()?;
+        let v = self.tree.pop();
+        self.front += 1;
+        self.len -= 1;
+        self.trim();
+        v
+    }
+    fn query(&self) -> Value {
+        self.tree.query()
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
@@ -0,0 +1,213 @@
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
+use crate::FifoWindow;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
+use crate::SubWindow;
+use alga::general::Operator;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// A segment tree over the window, where values are appended at the back and evicted at
+/// the front, and where every sub-range of the window can be aggregated in `O(log n)`.
+///
+/// Evicted leaves are not reused. Instead, the tree is rebuilt with the live values
+/// when it runs out of leaves at the back or becomes sparse, which amortizes to `O(1)`.
+#[derive(Clone)]
+pub struct SegTree<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// A flat binary tree, where node `i` has children `2i` and `2i+1`,
+    /// and where the leaves are stored at `capacity..2*capacity`.
+    tree: Vec<Value>,
+    capacity: usize,
+    /// Leaf index of the front of the window
+    front: usize,
+    /// Leaf index after the back of the window
+    back: usize,
+    op: PhantomData<BinOp>,
+}
+
+impl<Value, BinOp> SegTree<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Returns the time complexity of the operations of the window. Pushes and pops
+    /// update the path from a leaf to the root, and rebuild the tree when it runs out
+    /// of leaves or becomes sparse.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::amortized(Order::Logarithmic, Order::Linear),
+            pop: Bounds::amortized(Order::Logarithmic, Order::Linear),
+            query: Bounds::exact(Order::Logarithmic),
+        }
+    }
+    /// Returns a segment tree with room for `capacity` values before its first rebuild.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1).next_power_of_two();
+        Self {
+            tree: vec![Value::identity(); 2 * capacity],
+            capacity,
+            front: 0,
+            back: 0,
+            op: PhantomData,
+        }
+    }
+    /// Returns an iterator over the values in fifo order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        self.tree[self.capacity + self.front..self.capacity + self.back].iter()
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The tree is
+    /// moved into the snapshot as is, without copying it.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::from_tree(self)
+    }
+    /// Checks that the window fits within the leaves of the tree, that leaves outside the
+    /// window hold the identity, and that every inner node aggregates its children.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        if self.front > self.back || self.back > self.capacity {
+            return Err(InvariantError("window does not fit within the leaves"));
+        }
+        let leaves = &self.tree[self.capacity..];
+        if leaves[..self.front]
+            .iter()
+            .chain(leaves[self.back..].iter())
+            .any(|leaf| *leaf != Value::identity())
+        {
+            return Err(InvariantError(
+                "leaf outside the window is not the identity",
+            ));
+        }
+        if (1..self.capacity)
+            .any(|i| self.tree[i] != self.tree[2 * i].operate(&self.tree[2 * i + 1]))
+        {
+            return Err(InvariantError("inner node does not aggregate its children"));
+        }
+        Ok(())
+    }
+    /// Replaces the leaf at `idx` and updates its ancestors.
+    fn update(&mut self, idx: usize, v: Value) {
+        let mut i = idx + self.capacity;
+        self.tree[i] = v;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i].operate(&self.tree[2 * i + 1]);
+        }
+    }
+    /// Replaces the value at position `pos` of the window with `v`, and returns the
+    /// replaced value.
+    pub(crate) fn replace(&mut self, pos: usize, v: Value) -> Value {
+        let old = self.tree[self.capacity + self.front + pos].clone();
+        self.update(self.front + pos, v);
+        old
+    }
+    /// Aggregates the leaves at indices `start..end` in order.
+    fn range(&self, start: usize, end: usize) -> Value {
+        let mut left = Value::identity();
+        let mut right = Value::identity();
+        let mut l = start + self.capacity;
+        let mut r = end + self.capacity;
+        while l < r {
+            if l % 2 == 1 {
+                left = left.operate(&self.tree[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right = self.tree[r].operate(&right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        left.operate(&right)
+    }
+    /// Moves the live values to the front of a new tree with `capacity` leaves.
+    fn rebuild(&mut self, capacity: usize) {
+        let mut new = Self::with_capacity(capacity);
+        let leaves = &self.tree[self.capacity + self.front..self.capacity + self.back];
+        new.back = leaves.len();
+        new.tree[new.capacity..new.capacity + new.back].clone_from_slice(leaves);
+        for i in (1..new.capacity).rev() {
+            new.tree[i] = new.tree[2 * i].operate(&new.tree[2 * i + 1]);
+        }
+        *self = new;
+    }
+}
+
+impl<Value, BinOp> FifoWindow<Value, BinOp> for SegTree<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self::with_capacity(2)
+    }
+    fn push(&mut self, v: Value) {
+        if self.back == self.capacity {
+            self.rebuild(2 * (self.len() + 1));
+        }
+        self.update(self.back, v);
+        self.back += 1;
+    }
+    fn pop(&mut self) -> Option<Value> {
+        if self.is_empty() {
+            return None;
+        }
+        let v = self.tree[self.capacity + self.front].clone();
+        self.update(self.front, Value::identity());
+        self.front += 1;
+        if self.len() <= self.capacity / 4 && self.capacity > 2 {
+            self.rebuild(2 * self.len());
+        }
+        Some(v)
+    }
+    fn query(&self) -> Value {
+        self.range(self.front, self.back)
+    }
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+    fn is_empty(&self) -> bool {
+        self.front == self.back
+    }
+}
+
+impl<Value, BinOp> SubWindow<usize, Value> for SegTree<Value, BinOp>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    /// Aggregates the values at positions `range`, counted from the front of the window.
+    fn range_query(&self, range: Range<usize>) -> Value {
+        let end = std::cmp::min(range.end, self.len());
+        if range.start >= end {
+            return Value::identity();
+        }
+        self.range(self.front + range.start, self.front + end)
+    }
+}
+
+impl_eq_by_values! {
+    SegTree<Value, BinOp>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+}
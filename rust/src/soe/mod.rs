@@ -1,5 +1,12 @@
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
 use crate::FifoWindow;
-use alga::general::AbstractGroup;
+use crate::Group;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
 use alga::general::Operator;
 use std::collections::VecDeque;
 use std::marker::PhantomData;
@@ -7,7 +14,7 @@ use std::marker::PhantomData;
 #[derive(Clone)]
 pub struct SoE<Value, BinOp>
 where
-    Value: AbstractGroup<BinOp> + Clone,
+    Value: Group<BinOp>,
     BinOp: Operator,
 {
     stack: VecDeque<Value>,
@@ -17,7 +24,7 @@ where
 
 impl<Value, BinOp> FifoWindow<Value, BinOp> for SoE<Value, BinOp>
 where
-    Value: AbstractGroup<BinOp> + Clone,
+    Value: Group<BinOp>,
     BinOp: Operator,
 {
     fn new() -> Self {
@@ -31,9 +38,14 @@ where
         self.agg = self.agg.operate(&v);
         self.stack.push_back(v);
     }
+    /// Returns the aggregate which is updated by the push.
+    fn push_query(&mut self, v: Value) -> Value {
+        self.push(v);
+        self.agg.clone()
+    }
     fn pop(&mut self) -> Option<Value> {
         if let Some(top) = self.stack.pop_front() {
-            self.agg = self.agg.operate(&top.two_sided_inverse());
+            self.agg = top.two_sided_inverse().operate(&self.agg);
             Some(top)
         } else {
             None
@@ -49,3 +61,80 @@ where
         self.stack.is_empty()
     }
 }
+
+impl<Value, BinOp> SoE<Value, BinOp>
+where
+    Value: Group<BinOp>,
+    BinOp: Operator,
+{
+    /// Returns the time complexity of the operations of the window. Pops subtract the
+    /// evicted value from the aggregate.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::exact(Order::Constant),
+            pop: Bounds::exact(Order::Constant),
+            query: Bounds::exact(Order::Constant),
+        }
+    }
+    /// Releases unused memory and recalculates the aggregate from scratch,
+    /// discarding any error accumulated by inexact inverses (e.g., of floats).
+    pub fn compact(&mut self) {
+        self.stack.shrink_to_fit();
+        self.agg = self
+            .stack
+            .iter()
+            .fold(Value::identity(), |acc, elem| acc.operate(elem));
+    }
+    /// Returns an iterator over the values in fifo order.
+    fn values(&self) -> impl Iterator<Item = &Value> {
+        self.stack.iter()
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The values
+    /// are copied into a segment tree, so that sub-ranges can be aggregated without
+    /// inverses, which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Checks that the aggregate matches the values. Inexact inverses (e.g., of floats)
+    /// may violate this invariant until the window is compacted.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        let agg = self
+            .stack
+            .iter()
+            .fold(Value::identity(), |acc, elem| acc.operate(elem));
+        if agg != self.agg {
+            return Err(InvariantError("aggregate does not match the values"));
+        }
+        Ok(())
+    }
+    /// Combines all values except the newest one and returns the result, by
+    /// subtracting the newest value from the aggregate.
+    pub fn query_excluding_newest(&self) -> Value {
+        match self.stack.back() {
+            Some(newest) => self.agg.operate(&newest.two_sided_inverse()),
+            None => Value::identity(),
+        }
+    }
+    /// Returns the number of elements the window can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+}
+
+impl_eq_by_values! {
+    SoE<Value, BinOp>
+    where
+        Value: Group<BinOp>,
+        BinOp: Operator,
+}
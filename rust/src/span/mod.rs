@@ -0,0 +1,58 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::ops::RangeInclusive;
+
+/// A window adaptor which counts the pushed and popped values, so that every value has
+/// an absolute position in the stream, i.e., the number of values pushed before it.
+#[derive(Clone)]
+pub struct Spanned<Window> {
+    window: Window,
+    pushed: usize,
+    popped: usize,
+}
+
+impl<Window> Spanned<Window> {
+    /// Returns the positions of the oldest and newest values in the window, or `None`
+    /// if the window is empty.
+    pub fn span(&self) -> Option<RangeInclusive<usize>> {
+        if self.pushed == self.popped {
+            None
+        } else {
+            Some(self.popped..=self.pushed - 1)
+        }
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for Spanned<Window>
+where
+    Window: FifoWindow<Value, BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            pushed: 0,
+            popped: 0,
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.pushed += 1;
+        self.window.push(v)
+    }
+    fn pop(&mut self) -> Option<Value> {
+        let v = self.window.pop();
+        if v.is_some() {
+            self.popped += 1;
+        }
+        v
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
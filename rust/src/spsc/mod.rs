@@ -0,0 +1,160 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::cell::UnsafeCell;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// Bit of the middle slot which is set when it holds an aggregate the reader has not seen.
+const FRESH: usize = 0b100;
+/// Bits of the middle slot which hold the index of its buffer.
+const INDEX: usize = 0b011;
+
+/// A triple buffer, where the writer and the reader each own one buffer, and the
+/// third buffer is exchanged between them through the atomic `middle` slot.
+struct Shared<Value> {
+    buffers: [UnsafeCell<Value>; 3],
+    middle: AtomicUsize,
+}
+
+// The writer only accesses the buffer it owns, and the reader only accesses the
+// buffer it owns. Ownership of a buffer is only transferred by swapping `middle`.
+unsafe impl<Value: Send> Sync for Shared<Value> {}
+
+/// A window adaptor which lets one producer thread push and pop values while
+/// one consumer thread reads the aggregate, without locking.
+///
+/// The producer publishes a snapshot of the aggregate after every `n`th push or
+/// pop (see `publish_every`), and the consumer reads the latest published snapshot.
+/// The snapshot therefore lags the window by at most `n - 1` operations, plus any
+/// operations which the producer performs concurrently with the read. Call
+/// `publish` to publish the current aggregate immediately.
+pub struct SyncWindow<Window, Value> {
+    window: Window,
+    shared: Arc<Shared<Value>>,
+    back: usize,
+    every: usize,
+    unpublished: usize,
+}
+
+/// The consumer side of a `SyncWindow`, which reads the latest published aggregate.
+pub struct Reader<Value> {
+    shared: Arc<Shared<Value>>,
+    front: usize,
+}
+
+impl<Window, Value> SyncWindow<Window, Value> {
+    /// Wraps `window` and returns the producer and consumer sides of the adaptor.
+    /// The aggregate of `window` is published immediately.
+    pub fn new<BinOp>(window: Window) -> (Self, Reader<Value>)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        Value: Clone,
+        BinOp: Operator,
+    {
+        let agg = window.query();
+        let shared = Arc::new(Shared {
+            buffers: [
+                UnsafeCell::new(agg.clone()),
+                UnsafeCell::new(agg.clone()),
+                UnsafeCell::new(agg),
+            ],
+            middle: AtomicUsize::new(1),
+        });
+        let reader = Reader {
+            shared: shared.clone(),
+            front: 2,
+        };
+        let writer = Self {
+            window,
+            shared,
+            back: 0,
+            every: 1,
+            unpublished: 0,
+        };
+        (writer, reader)
+    }
+    /// Publishes the aggregate after every `n`th push or pop instead of after every one.
+    pub fn publish_every(mut self, n: usize) -> Self {
+        assert!(n > 0, "must publish at least every n > 0 operations");
+        self.every = n;
+        self
+    }
+    /// Pushes `v` into the window, and publishes the aggregate if it is due.
+    pub fn push<BinOp>(&mut self, v: Value)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.push(v);
+        self.tick::<BinOp>();
+    }
+    /// Pops the oldest value from the window, and publishes the aggregate if it is due.
+    pub fn pop<BinOp>(&mut self)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.pop();
+        self.tick::<BinOp>();
+    }
+    /// Returns the current aggregate of the window, which may not be published yet.
+    pub fn query<BinOp>(&self) -> Value
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.query()
+    }
+    /// Publishes the current aggregate of the window to the reader.
+    pub fn publish<BinOp>(&mut self)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let agg = self.window.query();
+        // The writer owns the back buffer until it is swapped into the middle slot.
+        unsafe { *self.shared.buffers[self.back].get() = agg };
+        let prev = self.shared.middle.swap(self.back | FRESH, Ordering::AcqRel);
+        self.back = prev & INDEX;
+        self.unpublished = 0;
+    }
+    /// Returns the number of values in the window.
+    pub fn len<BinOp>(&self) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.len()
+    }
+    /// Returns `true` if the window contains no values.
+    pub fn is_empty<BinOp>(&self) -> bool
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.is_empty()
+    }
+    fn tick<BinOp>(&mut self)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.unpublished += 1;
+        if self.unpublished >= self.every {
+            self.publish::<BinOp>();
+        }
+    }
+}
+
+impl<Value: Clone> Reader<Value> {
+    /// Returns the latest aggregate published by the producer.
+    pub fn load(&mut self) -> Value {
+        if self.shared.middle.load(Ordering::Relaxed) & FRESH != 0 {
+            let prev = self.shared.middle.swap(self.front, Ordering::AcqRel);
+            self.front = prev & INDEX;
+        }
+        // The reader owns the front buffer until it is swapped into the middle slot.
+        unsafe { (*self.shared.buffers[self.front].get()).clone() }
+    }
+}
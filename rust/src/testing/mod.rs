@@ -0,0 +1,39 @@
+use crate::FifoWindow;
+use crate::Monoid;
+use alga::general::Operator;
+use rand::Rng;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// Pushes `values` into a `Window` while randomly interleaving pops, and checks after
+/// every step that the window's query equals a recalculation from scratch over a
+/// reference `VecDeque`. Panics with the step index on the first mismatch.
+pub fn check_random_workload<Window, Value, BinOp, I>(values: I)
+where
+    Window: FifoWindow<Value, BinOp>,
+    Value: Monoid<BinOp> + PartialEq + Debug,
+    BinOp: Operator,
+    I: IntoIterator<Item = Value>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = VecDeque::new();
+    for (step, v) in values.into_iter().enumerate() {
+        window.push(v.clone());
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .fold(Value::identity(), |acc, elem| acc.operate(elem));
+        assert_eq!(
+            window.len(),
+            reference.len(),
+            "length mismatch at step {}",
+            step
+        );
+        assert_eq!(window.query(), expected, "query mismatch at step {}", step);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::FifoWindow;
+use crate::Monoid;
+use alga::general::Operator;
+
+/// A window adaptor which, alongside the sliding aggregate of `window`, aggregates
+/// every consecutive bucket of `bucket_size` pushed values into a tumbling aggregate,
+/// which can be drained once the bucket is complete.
+#[derive(Clone)]
+pub struct TumblingSlidingWindow<Window, Value> {
+    window: Window,
+    bucket_size: usize,
+    /// The aggregate and number of values of the current incomplete bucket.
+    bucket: Value,
+    bucket_len: usize,
+    tumbled: Vec<Value>,
+}
+
+impl<Window, Value> TumblingSlidingWindow<Window, Value> {
+    /// Returns an adaptor which aggregates the values pushed into `window` in buckets
+    /// of `bucket_size` values.
+    pub fn new<BinOp>(window: Window, bucket_size: usize) -> Self
+    where
+        Window: FifoWindow<Value, BinOp>,
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+    {
+        assert!(bucket_size > 0, "bucket size must be positive");
+        Self {
+            window,
+            bucket_size,
+            bucket: Value::identity(),
+            bucket_len: 0,
+            tumbled: Vec::new(),
+        }
+    }
+    /// Inserts a value at the back of the window and into the current bucket, which is
+    /// completed if it holds `bucket_size` values.
+    pub fn push<BinOp>(&mut self, v: Value)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+    {
+        self.bucket = self.bucket.operate(&v);
+        self.bucket_len += 1;
+        if self.bucket_len == self.bucket_size {
+            let bucket = std::mem::replace(&mut self.bucket, Value::identity());
+            self.tumbled.push(bucket);
+            self.bucket_len = 0;
+        }
+        self.window.push(v);
+    }
+    /// Removes a value at the front of the window (if any). Buckets are not affected.
+    pub fn pop<BinOp>(&mut self) -> Option<Value>
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.pop()
+    }
+    /// Combines the values of the sliding window in fifo order and returns the result.
+    pub fn query<BinOp>(&self) -> Value
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.query()
+    }
+    /// Returns the number of elements inside the sliding window.
+    pub fn len<BinOp>(&self) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.len()
+    }
+    /// Returns true if the sliding window contains no elements.
+    pub fn is_empty<BinOp>(&self) -> bool
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.is_empty()
+    }
+    /// Returns the aggregates of the buckets which were completed since the last
+    /// drain, in the order they were completed.
+    pub fn drain_tumbled(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.tumbled)
+    }
+    /// Returns the number of values in the current incomplete bucket.
+    pub fn bucket_len(&self) -> usize {
+        self.bucket_len
+    }
+}
@@ -1,10 +1,22 @@
+use crate::alloc::Allocator;
+use crate::alloc::Global;
+use crate::columns::Columnar;
+use crate::complexity::Bounds;
+use crate::complexity::Complexity;
+use crate::complexity::Order;
+use crate::frozen::FrozenWindow;
 use crate::FifoWindow;
-use alga::general::AbstractMonoid;
+#[cfg(feature = "inspect")]
+use crate::InvariantError;
+use crate::Monoid;
 use alga::general::Operator;
 use std::marker::PhantomData;
 
+#[cfg(feature = "simd")]
+mod simd;
+
 #[derive(Clone)]
-struct Item<Value>
+pub(crate) struct Item<Value>
 where
     Value: Clone,
 {
@@ -12,43 +24,202 @@ where
     val: Value,
 }
 
-#[derive(Clone)]
-pub struct TwoStacks<Value, BinOp>
+/// Moves the values of the back stack onto the empty front stack in reverse,
+/// and computes their aggregates. Values can specialize this with the `simd` feature.
+pub(crate) trait Flip<BinOp>: Clone {
+    fn flip<A>(
+        back: &mut alloc_type!(Vec<Item<Self>, A>),
+        front: &mut alloc_type!(Vec<Item<Self>, A>),
+    ) where
+        A: Allocator + Clone;
+}
+
+#[cfg(not(feature = "simd"))]
+impl<Value, BinOp> Flip<BinOp> for Value
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
 {
-    front: Vec<Item<Value>>,
-    back: Vec<Item<Value>>,
-    op: PhantomData<BinOp>,
+    fn flip<A>(
+        back: &mut alloc_type!(Vec<Item<Self>, A>),
+        front: &mut alloc_type!(Vec<Item<Self>, A>),
+    ) where
+        A: Allocator + Clone,
+    {
+        flip_scalar::<Value, BinOp, A>(back, front)
+    }
 }
 
-impl<Value, BinOp> FifoWindow<Value, BinOp> for TwoStacks<Value, BinOp>
+/// Flips the stacks by combining one value at a time.
+// The allocator is only part of the stack types with the `allocator_api` feature.
+#[allow(clippy::extra_unused_type_parameters)]
+fn flip_scalar<Value, BinOp, A>(
+    back: &mut alloc_type!(Vec<Item<Value>, A>),
+    front: &mut alloc_type!(Vec<Item<Value>, A>),
+) where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    while let Some(top) = back.pop() {
+        let agg = match front.last() {
+            Some(item) => top.val.operate(&item.agg),
+            None => top.val.clone(),
+        };
+        front.push(Item { agg, val: top.val })
+    }
+}
+
+pub struct TwoStacks<Value, BinOp, A = Global>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
+    A: Allocator + Clone,
 {
-    fn new() -> Self {
+    front: alloc_type!(Vec<Item<Value>, A>),
+    back: alloc_type!(Vec<Item<Value>, A>),
+    op: PhantomData<(BinOp, A)>,
+}
+
+impl<Value, BinOp, A> Clone for TwoStacks<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+            op: PhantomData,
+        }
+    }
+}
+
+impl<Value, BinOp, A> TwoStacks<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone,
+{
+    /// Returns the time complexity of the operations of the window. Pops flip the back
+    /// stack onto the front stack when it runs empty.
+    pub const fn complexity() -> Complexity {
+        Complexity {
+            push: Bounds::exact(Order::Constant),
+            pop: Bounds::amortized(Order::Constant, Order::Linear),
+            query: Bounds::exact(Order::Constant),
+        }
+    }
+    /// Returns an empty window whose stacks are allocated by `alloc`.
+    pub fn new_in(alloc: A) -> Self {
         Self {
-            front: Vec::new(),
-            back: Vec::new(),
+            front: alloc_new!(Vec, alloc.clone()),
+            back: alloc_new!(Vec, alloc),
             op: PhantomData,
         }
     }
+    /// Returns the partial aggregates of the stacks in fifo order. The aggregate of a
+    /// value on the front stack combines it with all newer values on the front stack,
+    /// and the aggregate of a value on the back stack combines it with all older values
+    /// on the back stack.
+    #[cfg(feature = "inspect")]
+    pub fn partials(&self) -> Vec<Value> {
+        self.front
+            .iter()
+            .rev()
+            .chain(self.back.iter())
+            .map(|item| item.agg.clone())
+            .collect()
+    }
+    /// Checks that the aggregate of every value on the front stack combines it with all
+    /// newer values below it, and that the aggregate of every value on the back stack
+    /// combines it with all older values below it.
+    #[cfg(feature = "inspect")]
+    pub fn check_invariants(&self) -> Result<(), InvariantError>
+    where
+        Value: PartialEq,
+    {
+        let mut agg = Value::identity();
+        for item in self.front.iter() {
+            agg = item.val.operate(&agg);
+            if item.agg != agg {
+                return Err(InvariantError("inconsistent aggregate on the front stack"));
+            }
+        }
+        let mut agg = Value::identity();
+        for item in self.back.iter() {
+            agg = agg.operate(&item.val);
+            if item.agg != agg {
+                return Err(InvariantError("inconsistent aggregate on the back stack"));
+            }
+        }
+        Ok(())
+    }
+    /// Returns an iterator over the values in fifo order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        self.front
+            .iter()
+            .rev()
+            .chain(self.back.iter())
+            .map(|item| &item.val)
+    }
+    /// Returns the values in fifo order, split into one column per field.
+    pub fn to_columns(&self) -> Value::Columns
+    where
+        Value: Columnar,
+    {
+        crate::columns::collect(self.values())
+    }
+    /// Returns an immutable snapshot of the window, which is cheap to clone. The values
+    /// of both stacks are copied into a segment tree, which takes O(n) time.
+    pub fn freeze(self) -> FrozenWindow<Value, BinOp> {
+        FrozenWindow::new(self.values())
+    }
+    /// Combines all values except the newest one in fifo order and returns the result.
+    /// Takes constant time, unless the back stack is empty, in which case the newest
+    /// value is at the bottom of the front stack and the rest of it is recombined.
+    pub fn query_excluding_newest(&self) -> Value {
+        match self.back.len() {
+            0 => self
+                .front
+                .iter()
+                .skip(1)
+                .rev()
+                .fold(Value::identity(), |acc, item| acc.operate(&item.val)),
+            n => Self::agg(&self.front).operate(&Self::agg(&self.back[..n - 1])),
+        }
+    }
+}
+
+impl<Value, BinOp, A> FifoWindow<Value, BinOp> for TwoStacks<Value, BinOp, A>
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+    A: Allocator + Clone + Default,
+{
+    fn new() -> Self {
+        Self::new_in(A::default())
+    }
     fn push(&mut self, v: Value) {
         self.back.push(Item {
             agg: Self::agg(&self.back).operate(&v),
             val: v,
         });
     }
+    /// Combines the aggregate of the front stack with the new aggregate of the back
+    /// stack, without recalculating the latter.
+    fn push_query(&mut self, v: Value) -> Value {
+        let agg = Self::agg(&self.back).operate(&v);
+        self.back.push(Item {
+            agg: agg.clone(),
+            val: v,
+        });
+        Self::agg(&self.front).operate(&agg)
+    }
     fn pop(&mut self) -> Option<Value> {
         if self.front.is_empty() {
-            while let Some(top) = self.back.pop() {
-                self.front.push(Item {
-                    agg: top.val.operate(&Self::agg(&self.front)),
-                    val: top.val,
-                })
-            }
+            <Value as Flip<BinOp>>::flip::<A>(&mut self.back, &mut self.front);
         }
         self.front.pop().map(|item| item.val)
     }
@@ -63,10 +234,11 @@ where
     }
 }
 
-impl<Value, BinOp> TwoStacks<Value, BinOp>
+impl<Value, BinOp, A> TwoStacks<Value, BinOp, A>
 where
-    Value: AbstractMonoid<BinOp> + Clone,
+    Value: Monoid<BinOp>,
     BinOp: Operator,
+    A: Allocator + Clone,
 {
     #[inline(always)]
     fn agg(stack: &[Item<Value>]) -> Value {
@@ -77,3 +249,11 @@ where
         }
     }
 }
+
+impl_eq_by_values! {
+    TwoStacks<Value, BinOp, A>
+    where
+        Value: Monoid<BinOp>,
+        BinOp: Operator,
+        A: Allocator + Clone,
+}
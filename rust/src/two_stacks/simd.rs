@@ -0,0 +1,72 @@
+use super::flip_scalar;
+use super::Flip;
+use super::Item;
+use crate::alloc::Allocator;
+use crate::ops::Sum;
+use crate::Monoid;
+use alga::general::Operator;
+use std::simd::simd_swizzle;
+use std::simd::Simd;
+
+const LANES: usize = 4;
+
+impl<Value, BinOp> Flip<BinOp> for Value
+where
+    Value: Monoid<BinOp>,
+    BinOp: Operator,
+{
+    default fn flip<A>(
+        back: &mut alloc_type!(Vec<Item<Self>, A>),
+        front: &mut alloc_type!(Vec<Item<Self>, A>),
+    ) where
+        A: Allocator + Clone,
+    {
+        flip_scalar::<Value, BinOp, A>(back, front)
+    }
+}
+
+/// Implements the flip of `Sum` for primitive integers with a vectorized prefix sum
+/// over the aggregates of the front stack. Overflow panics when overflow checks are
+/// enabled, and wraps around otherwise, like the scalar flip.
+macro_rules! impl_flip_simd {
+    { $($ty:ty),* } => {
+        $(
+            impl Flip<Sum> for $ty {
+                fn flip<A>(
+                    back: &mut alloc_type!(Vec<Item<Self>, A>),
+                    front: &mut alloc_type!(Vec<Item<Self>, A>),
+                ) where
+                    A: Allocator + Clone,
+                {
+                    debug_assert!(front.is_empty());
+                    front.extend(back.drain(..).rev().map(|item| Item { agg: item.val, val: item.val }));
+                    let zero = Simd::<$ty, LANES>::splat(0);
+                    let mut carry = zero;
+                    let mut chunks = front.chunks_exact_mut(LANES);
+                    for chunk in &mut chunks {
+                        let mut x = Simd::from_array(std::array::from_fn(|i| chunk[i].agg));
+                        x += simd_swizzle!(x, zero, [4, 0, 1, 2]);
+                        x += simd_swizzle!(x, zero, [4, 4, 0, 1]);
+                        x += carry;
+                        carry = Simd::splat(x[LANES - 1]);
+                        for (item, agg) in chunk.iter_mut().zip(x.to_array().iter()) {
+                            item.agg = *agg;
+                        }
+                    }
+                    let mut agg = carry[0];
+                    for item in chunks.into_remainder() {
+                        agg = agg.wrapping_add(item.agg);
+                        item.agg = agg;
+                    }
+                    if cfg!(overflow_checks) {
+                        front.iter().fold(0 as $ty, |agg, item| {
+                            agg.checked_add(item.val).expect("attempt to add with overflow")
+                        });
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_flip_simd! { i32, i64, u32, u64 }
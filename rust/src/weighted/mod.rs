@@ -0,0 +1,98 @@
+use crate::FifoWindow;
+use alga::general::Operator;
+use std::collections::VecDeque;
+
+/// A window adaptor where every element carries a weight, e.g., its size in bytes,
+/// so that elements can be evicted until the total weight fits within a budget.
+///
+/// Values which are pushed through `FifoWindow::push` have zero weight.
+#[derive(Clone)]
+pub struct WeightedWindow<Window> {
+    window: Window,
+    weights: VecDeque<u64>,
+    total: u64,
+}
+
+impl<Window> WeightedWindow<Window> {
+    /// Returns an adaptor which tracks the weights of the elements pushed into `window`.
+    /// Elements which are already inside `window` have zero weight.
+    pub fn new<Value, BinOp>(window: Window) -> Self
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let weights = std::iter::repeat_n(0, window.len()).collect();
+        Self {
+            window,
+            weights,
+            total: 0,
+        }
+    }
+    /// Inserts a value with a weight at the back of the window.
+    pub fn push_weighted<Value, BinOp>(&mut self, v: Value, weight: u64)
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        self.window.push(v);
+        self.weights.push_back(weight);
+        self.total += weight;
+    }
+    /// Removes a value and its weight at the front of the window (if any).
+    pub fn pop_weighted<Value, BinOp>(&mut self) -> Option<(Value, u64)>
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let v = self.window.pop()?;
+        let weight = self.weights.pop_front()?;
+        self.total -= weight;
+        Some((v, weight))
+    }
+    /// Pops the oldest elements until the total weight is at most `budget`,
+    /// and returns the number of popped elements.
+    pub fn evict_to<Value, BinOp>(&mut self, budget: u64) -> usize
+    where
+        Window: FifoWindow<Value, BinOp>,
+        BinOp: Operator,
+    {
+        let mut evicted = 0;
+        while self.total > budget && self.pop_weighted().is_some() {
+            evicted += 1;
+        }
+        evicted
+    }
+    /// Returns the total weight of the elements inside the window.
+    pub fn weight(&self) -> u64 {
+        self.total
+    }
+}
+
+impl<Window, Value, BinOp> FifoWindow<Value, BinOp> for WeightedWindow<Window>
+where
+    Window: FifoWindow<Value, BinOp>,
+    BinOp: Operator,
+{
+    fn new() -> Self {
+        Self {
+            window: Window::new(),
+            weights: VecDeque::new(),
+            total: 0,
+        }
+    }
+    fn push(&mut self, v: Value) {
+        self.push_weighted(v, 0);
+    }
+    fn pop(&mut self) -> Option<Value> {
+        self.pop_weighted().map(|(v, _)| v)
+    }
+    fn query(&self) -> Value {
+        self.window.query()
+    }
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
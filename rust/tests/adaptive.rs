@@ -0,0 +1,23 @@
+use swag::adaptive::Adaptive;
+use swag::ops::Sum;
+use swag::recalc::ReCalc;
+use swag::*;
+
+/// Pushes past the migration threshold of the adaptive window and back, checking every query.
+#[test]
+fn migration() {
+    let mut window = Adaptive::<i64, Sum>::with_threshold(8);
+    let mut reference = ReCalc::<i64, Sum>::new();
+    assert!(window.is_recalc());
+    for i in 0..20 {
+        window.push(i);
+        reference.push(i);
+        assert_eq!(window.query(), reference.query());
+    }
+    assert!(!window.is_recalc());
+    for _ in 0..20 {
+        assert_eq!(window.pop(), reference.pop());
+        assert_eq!(window.query(), reference.query());
+    }
+    assert!(window.is_recalc());
+}
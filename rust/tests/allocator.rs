@@ -0,0 +1,66 @@
+#![cfg(feature = "allocator_api")]
+#![feature(allocator_api)]
+
+use std::alloc::AllocError;
+use std::alloc::Allocator;
+use std::alloc::Global;
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use swag::ops::Max;
+use swag::*;
+
+/// An allocator which counts its live allocations and delegates to `Global`.
+#[derive(Clone, Default)]
+struct Counting {
+    live: Rc<Cell<isize>>,
+}
+
+unsafe impl Allocator for Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.live.set(self.live.get() + 1);
+        Global.allocate(layout)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.live.set(self.live.get() - 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+fn workload<Window: FifoWindow<i64, Max>>(mut window: Window) {
+    let mut reference = std::collections::VecDeque::new();
+    for i in 0..1000 {
+        let v = (i * 7919) % 1000;
+        window.push(v);
+        reference.push_back(v);
+        if i % 3 == 2 {
+            assert_eq!(window.pop(), reference.pop_front());
+        }
+        assert_eq!(window.query(), *reference.iter().max().unwrap());
+    }
+}
+
+#[test]
+fn two_stacks() {
+    let alloc = Counting::default();
+    workload(two_stacks::TwoStacks::<i64, Max, _>::new_in(alloc.clone()));
+    assert_eq!(alloc.live.get(), 0);
+    let window = two_stacks::TwoStacks::<i64, Max, _>::new_in(alloc.clone());
+    let mut window = window.clone();
+    window.push(1);
+    assert!(alloc.live.get() > 0);
+    drop(window);
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn recalc() {
+    let alloc = Counting::default();
+    let mut window = recalc::ReCalc::<i64, Max, _>::new_in(alloc.clone());
+    window.push(1);
+    assert!(alloc.live.get() > 0);
+    drop(window);
+    workload(recalc::ReCalc::<i64, Max, _>::new_in(alloc.clone()));
+    assert_eq!(alloc.live.get(), 0);
+}
@@ -0,0 +1,47 @@
+use rand::Rng;
+use swag::ops::Pair;
+use swag::ops::Sum;
+use swag::*;
+
+/// Macro for checking the columns of a window of `(i64, f64)` pairs for different algorithms.
+macro_rules! test_columns {
+    { $($name:ident => $module:ident::$algorithm:ident),* } => {
+        $(
+            #[test]
+            fn $name() {
+                let mut rng = rand::thread_rng();
+                let mut window = swag::$module::$algorithm::<(i64, f64), Pair<Sum, Sum>>::new();
+                let mut reference = std::collections::VecDeque::new();
+                for _ in 0..1000 {
+                    let v = (rng.gen_range(-100, 100), rng.gen_range(-1.0, 1.0));
+                    window.push(v);
+                    reference.push_back(v);
+                    if rng.gen_bool(0.5) {
+                        window.query();
+                    }
+                    while rng.gen_bool(0.5) && !reference.is_empty() {
+                        window.pop();
+                        reference.pop_front();
+                    }
+                }
+                let (ints, floats) = window.to_columns();
+                assert_eq!(ints.len(), reference.len());
+                assert_eq!(floats.len(), reference.len());
+                for ((i, f), (x, y)) in ints.iter().zip(floats.iter()).zip(reference.iter()) {
+                    assert_eq!(i, x);
+                    assert_eq!(f, y);
+                }
+            }
+        )*
+    }
+}
+
+test_columns! {
+    recalc => recalc::ReCalc,
+    soe => soe::SoE,
+    reactive => reactive::Reactive,
+    two_stacks => two_stacks::TwoStacks,
+    flatfit => flatfit::FlatFIT,
+    adaptive => adaptive::Adaptive,
+    segtree => segtree::SegTree
+}
@@ -8,34 +8,34 @@ use alga::general::Identity;
 use alga::general::Operator;
 use alga::general::TwoSidedInverse;
 
-/// Abstract Algebra Lattice:
-/// Borrowed from https://docs.rs/alga/0.9.3/alga/general/index.html
-///
-///                           AbstractMagma
-///                                |
-///                        _______/ \______
-///                       /                \
-///                 divisibility       associativity
-///                      |                  |
-///                      V                  V
-///                AbstractQuasigroup AbstractSemigroup
-///                      |                  |
-///                  identity            identity
-///                      |                  |
-///                      V                  V
-///                 AbstractLoop       AbstractMonoid
-///                      |                  |
-///                 associativity     invertibility
-///                       \______   _______/
-///                              \ /
-///                               |
-///                               V
-///                         AbstractGroup
-///                               |
-///                         commutativity
-///                               |
-///                               V
-///                     AbstractGroupAbelian
+// Abstract Algebra Lattice:
+// Borrowed from https://docs.rs/alga/0.9.3/alga/general/index.html
+//
+//                           AbstractMagma
+//                                |
+//                        _______/ \______
+//                       /                \
+//                 divisibility       associativity
+//                      |                  |
+//                      V                  V
+//                AbstractQuasigroup AbstractSemigroup
+//                      |                  |
+//                  identity            identity
+//                      |                  |
+//                      V                  V
+//                 AbstractLoop       AbstractMonoid
+//                      |                  |
+//                 associativity     invertibility
+//                       \______   _______/
+//                              \ /
+//                               |
+//                               V
+//                         AbstractGroup
+//                               |
+//                         commutativity
+//                               |
+//                               V
+//                     AbstractGroupAbelian
 
 /// An integer value
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -78,6 +78,7 @@ impl AbstractMonoid<Sum> for Int {}
 impl AbstractQuasigroup<Sum> for Int {}
 impl AbstractLoop<Sum> for Int {}
 impl AbstractGroup<Sum> for Int {}
+swag::infallible!(Sum => Int);
 
 /// Binary operator for calculating the maximum Int.
 /// Has the following properties:
@@ -94,7 +95,7 @@ impl Operator for Max {
 
 impl Identity<Max> for Int {
     fn identity() -> Int {
-        Int(std::i64::MIN)
+        Int(i64::MIN)
     }
 }
 
@@ -0,0 +1,66 @@
+use swag::compare::run_parallel;
+use swag::compare::Op;
+use swag::ops::Max;
+use swag::*;
+
+/// A window which forgets the `n`th pushed value.
+#[derive(Clone)]
+struct Forgetful {
+    values: std::collections::VecDeque<i64>,
+    pushes: usize,
+}
+
+impl FifoWindow<i64, Max> for Forgetful {
+    fn new() -> Self {
+        Self {
+            values: std::collections::VecDeque::new(),
+            pushes: 0,
+        }
+    }
+    fn push(&mut self, v: i64) {
+        self.pushes += 1;
+        if self.pushes != 7 {
+            self.values.push_back(v);
+        }
+    }
+    fn pop(&mut self) -> Option<i64> {
+        self.values.pop_front()
+    }
+    fn query(&self) -> i64 {
+        self.values.iter().copied().max().unwrap_or(i64::MIN)
+    }
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+fn workload() -> Vec<Op<i64>> {
+    (0..20)
+        .flat_map(|i| vec![Op::Push(i), Op::Push(-i), Op::Pop])
+        .collect()
+}
+
+#[test]
+fn agree() {
+    type Left = two_stacks::TwoStacks<i64, Max>;
+    type Right = reactive::Reactive<i64, Max>;
+    assert_eq!(run_parallel::<Left, Right, _, _>(&workload()), None);
+    assert_eq!(run_parallel::<Left, Left, _, _>(&[]), None);
+}
+
+#[test]
+fn diverge() {
+    type Correct = recalc::ReCalc<i64, Max>;
+    // The 7th push is the value 3 at index 9
+    assert_eq!(
+        run_parallel::<Correct, Forgetful, _, _>(&workload()),
+        Some(9)
+    );
+    assert_eq!(
+        run_parallel::<Forgetful, Correct, _, _>(&workload()),
+        Some(9)
+    );
+}
@@ -0,0 +1,69 @@
+use swag::complexity::Bounds;
+use swag::complexity::Complexity;
+use swag::complexity::Order::*;
+use swag::ops::Sum;
+
+#[test]
+fn documented_complexity() {
+    let constant = Bounds::exact(Constant);
+    let logarithmic = Bounds::exact(Logarithmic);
+    let linear = Bounds::exact(Linear);
+    let amortized_constant = Bounds::amortized(Constant, Linear);
+    let amortized_logarithmic = Bounds::amortized(Logarithmic, Linear);
+    assert_eq!(
+        swag::recalc::ReCalc::<i64, Sum>::complexity(),
+        Complexity {
+            push: constant,
+            pop: constant,
+            query: linear
+        }
+    );
+    assert_eq!(
+        swag::soe::SoE::<i64, Sum>::complexity(),
+        Complexity {
+            push: constant,
+            pop: constant,
+            query: constant
+        }
+    );
+    assert_eq!(
+        swag::two_stacks::TwoStacks::<i64, Sum>::complexity(),
+        Complexity {
+            push: constant,
+            pop: amortized_constant,
+            query: constant
+        }
+    );
+    assert_eq!(
+        swag::reactive::Reactive::<i64, Sum>::complexity(),
+        Complexity {
+            push: amortized_logarithmic,
+            pop: amortized_logarithmic,
+            query: logarithmic
+        }
+    );
+    assert_eq!(
+        swag::flatfit::FlatFIT::<i64, Sum>::complexity(),
+        Complexity {
+            push: amortized_constant,
+            pop: amortized_constant,
+            query: amortized_constant
+        }
+    );
+    assert_eq!(
+        swag::segtree::SegTree::<i64, Sum>::complexity(),
+        Complexity {
+            push: amortized_logarithmic,
+            pop: amortized_logarithmic,
+            query: logarithmic
+        }
+    );
+    assert_eq!(
+        swag::adaptive::Adaptive::<i64, Sum>::complexity(),
+        Complexity {
+            push: constant,
+            pop: amortized_constant,
+            query: constant
+        }
+    );
+}
@@ -0,0 +1,38 @@
+use rand::Rng;
+use swag::dynamic::DynWindow;
+use swag::ops::Sum;
+use swag::*;
+
+/// Compares a window with a runtime sum closure against `Window` under the static
+/// `Sum` operator.
+fn compare_with<Window>()
+where
+    Window: FifoWindow<i64, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut dynamic = DynWindow::new(0, |a: &i64, b: &i64| a + b);
+    let mut window = Window::new();
+    for _ in 0..1_000 {
+        let v = rng.gen_range(1, 5);
+        dynamic.push(v);
+        window.push(v);
+        while rng.gen_bool(0.5) {
+            dynamic.pop();
+            window.pop();
+        }
+        assert_eq!(dynamic.len(), window.len());
+        assert_eq!(dynamic.query(), window.query());
+    }
+}
+
+/// Compares a runtime closure against the static operator of every algorithm.
+#[test]
+fn compare() {
+    compare_with::<recalc::ReCalc<i64, Sum>>();
+    compare_with::<soe::SoE<i64, Sum>>();
+    compare_with::<reactive::Reactive<i64, Sum>>();
+    compare_with::<two_stacks::TwoStacks<i64, Sum>>();
+    compare_with::<flatfit::FlatFIT<i64, Sum>>();
+    compare_with::<adaptive::Adaptive<i64, Sum>>();
+    compare_with::<segtree::SegTree<i64, Sum>>();
+}
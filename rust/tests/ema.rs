@@ -0,0 +1,47 @@
+use swag::ema::EmaWindow;
+use swag::DecayedWindow;
+
+/// The closed form `(1-a)^(n-1)*x1 + sum_{i>1} a*(1-a)^(n-i)*xi` of the average.
+fn closed_form(alpha: f64, values: &[f64]) -> f64 {
+    let n = values.len();
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let weight = if i == 0 { 1.0 } else { alpha };
+            weight * (1.0 - alpha).powi((n - 1 - i) as i32) * v
+        })
+        .sum()
+}
+
+#[test]
+fn closed_form_sequence() {
+    let values = (0..100)
+        .map(|i| (i as f64).sin() * 10.0)
+        .collect::<Vec<_>>();
+    for alpha in [0.1, 0.5, 0.9, 1.0].iter() {
+        let mut window = EmaWindow::new(*alpha);
+        assert_eq!(window.query(), None);
+        for (i, v) in values.iter().enumerate() {
+            window.push(*v);
+            let expected = closed_form(*alpha, &values[..=i]);
+            assert!((window.query().unwrap() - expected).abs() < 1e-9);
+        }
+        assert_eq!(window.len(), values.len());
+    }
+}
+
+#[test]
+fn constant_sequence() {
+    let mut window = EmaWindow::new(0.3);
+    for _ in 0..10 {
+        window.push(4.0);
+    }
+    assert!((window.query().unwrap() - 4.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn invalid_alpha() {
+    EmaWindow::new(0.0);
+}
@@ -1,4 +1,11 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use rand::rngs::StdRng;
 use rand::Rng;
+use rand::SeedableRng;
 use swag::*;
 
 mod common;
@@ -91,7 +98,7 @@ where
     for _ in values {
         window.pop();
     }
-    assert_eq!(window.query(), Int(std::i64::MIN));
+    assert_eq!(window.query(), Int(i64::MIN));
 }
 
 /// Fills a window with 1K elements and pushes/pops/queries 1K times.
@@ -175,14 +182,2065 @@ where
     assert_eq!(window.query(), Int(2));
 }
 
+/// Compares the windowed sum of complex numbers against a manual fold.
+fn test10<Window>()
+where
+    Window: FifoWindow<ops::Complex, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let values = (0..1_000)
+        .map(|_| ops::Complex::new(rng.gen_range(-5, 5) as f64, rng.gen_range(-5, 5) as f64))
+        .collect::<Vec<_>>();
+    let mut window = Window::new();
+    for (i, v) in values.iter().enumerate() {
+        window.push(*v);
+        if i % 3 == 0 {
+            window.pop();
+        }
+        let expected = values[i + 1 - window.len()..=i]
+            .iter()
+            .fold(ops::Complex::new(0.0, 0.0), |acc, x| {
+                ops::Complex::new(acc.re + x.re, acc.im + x.im)
+            });
+        assert_eq!(window.query(), expected);
+    }
+}
+
+/// Sums monetary amounts exactly, including after pops.
+#[cfg(feature = "rust_decimal")]
+fn test11<Window>()
+where
+    Window: FifoWindow<rust_decimal::Decimal, ops::Sum>,
+{
+    use rust_decimal::Decimal;
+    let mut window = Window::new();
+    let cents = [10, 20, 30, 1, 99, 7];
+    for c in cents.iter() {
+        window.push(Decimal::new(*c, 2));
+    }
+    assert_eq!(window.query(), Decimal::new(167, 2));
+    window.pop();
+    window.pop();
+    assert_eq!(window.query(), Decimal::new(137, 2));
+    window.pop();
+    window.pop();
+    window.pop();
+    assert_eq!(window.query(), Decimal::new(7, 2));
+}
+
+/// A custom value type which only implements alga's monoid laws.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Mod7(u8);
+
+impl Identity<Sum> for Mod7 {
+    fn identity() -> Mod7 {
+        Mod7(0)
+    }
+}
+
+impl AbstractMagma<Sum> for Mod7 {
+    fn operate(&self, other: &Self) -> Self {
+        Mod7((self.0 + other.0) % 7)
+    }
+}
+
+impl AbstractSemigroup<Sum> for Mod7 {}
+impl AbstractMonoid<Sum> for Mod7 {}
+
+/// Basic test for a custom value type implementing `Monoid`.
+fn test12<Window>()
+where
+    Window: FifoWindow<Mod7, Sum>,
+{
+    let mut window = Window::new();
+
+    assert_eq!(window.query(), Mod7(0));
+
+    window.push(Mod7(1));
+
+    assert_eq!(window.query(), Mod7(1));
+
+    window.push(Mod7(2));
+
+    assert_eq!(window.query(), Mod7(3));
+
+    window.push(Mod7(5));
+
+    assert_eq!(window.query(), Mod7(1));
+
+    window.pop();
+
+    assert_eq!(window.query(), Mod7(0));
+}
+
+/// Computes the sum and maximum in one window and compares against two separate windows.
+fn test13<Window>()
+where
+    Window: FifoWindow<(Int, Int), ops::Pair<Sum, Max>>,
+{
+    let values = synthesize(1_000);
+    let mut window = Window::new();
+    let mut sum = swag::two_stacks::TwoStacks::<Int, Sum>::new();
+    let mut max = swag::two_stacks::TwoStacks::<Int, Max>::new();
+    for (i, v) in values.into_iter().enumerate() {
+        window.push((v, v));
+        sum.push(v);
+        max.push(v);
+        if i % 2 == 0 {
+            window.pop();
+            sum.pop();
+            max.pop();
+        }
+        assert_eq!(window.query(), (sum.query(), max.query()));
+    }
+}
+
+/// Maps inputs through `|x| x*x` and compares against manual squared sums.
+fn test14<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let values = synthesize(1_000);
+    let mut window = swag::map::Map::new(Window::new(), |Int(x)| Int(x * x));
+    for (i, Int(v)) in values.iter().enumerate() {
+        window.push(Int(*v));
+        if i % 3 == 0 {
+            window.pop();
+        }
+        let expected = values[i + 1 - window.len()..=i]
+            .iter()
+            .fold(0, |acc, Int(x)| acc + x * x);
+        assert_eq!(window.query(), Int(expected));
+    }
+    assert!(!window.is_empty());
+    // The adaptor is itself a window, so it composes with other windows
+    assert_eq!(merge_windows(&window, &Window::new()), window.query());
+}
+
+/// Keeps only even values and compares against a manual filtered sum.
+fn test15<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let values = (0..1_000).map(Int).collect::<Vec<_>>();
+    let mut window = swag::filter::Filter::new(Window::new(), |Int(x): &Int| x % 2 == 0);
+    for (i, v) in values.iter().enumerate() {
+        window.push(*v);
+        if i % 3 == 0 {
+            // Values failing the predicate are returned unchanged
+            let front = values[i + 1 - window.len()];
+            let popped = window.pop();
+            if front.0 % 2 != 0 {
+                assert_eq!(popped, Some(front));
+            }
+        }
+        let expected = values[i + 1 - window.len()..=i]
+            .iter()
+            .filter(|Int(x)| x % 2 == 0)
+            .fold(0, |acc, Int(x)| acc + x);
+        assert_eq!(window.query(), Int(expected));
+    }
+    assert_eq!(window.len(), 1_000 - 334);
+}
+
+/// Runs the reusable randomized workload check for the built-in operators.
+#[cfg(feature = "testing")]
+fn test16<Window>()
+where
+    Window: FifoWindow<(Int, ops::Complex), ops::Pair<Sum, ops::Sum>>,
+{
+    let values = synthesize(1_000)
+        .into_iter()
+        .map(|Int(x)| (Int(x), ops::Complex::new(x as f64, -x as f64)));
+    swag::testing::check_random_workload::<Window, _, _, _>(values);
+}
+
+/// Runs the reusable randomized workload check for a non-invertible operator.
+#[cfg(feature = "testing")]
+fn test17<Window>()
+where
+    Window: FifoWindow<Int, Max>,
+{
+    swag::testing::check_random_workload::<Window, _, _, _>(synthesize(1_000));
+}
+
+/// Defines randomized workload checks, each of which lifts 1K randomly generated
+/// integers into values of a built-in operator.
+#[cfg(feature = "testing")]
+macro_rules! workload {
+    {
+        $(
+            $name:ident => $value:ty, $op:ty, $lift:expr
+        );*
+    } => {
+        $(
+            fn $name<Window>()
+            where
+                Window: FifoWindow<$value, $op>,
+            {
+                let values = synthesize(1_000).into_iter().map($lift);
+                swag::testing::check_random_workload::<Window, _, _, _>(values);
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "testing")]
+workload! {
+    workload_sum => i64, ops::Sum, |Int(x)| x;
+    workload_product => ops::ZeroCountProduct, ops::Product, |Int(x)| {
+        ops::ZeroCountProduct::from((x % 3 - 1) as f64)
+    };
+    workload_concat => ops::LastN<i64, 3>, ops::Concat, |Int(x)| ops::LastN::from(x);
+    workload_max => i64, ops::Max, |Int(x)| x;
+    workload_min => i64, ops::Min, |Int(x)| x;
+    workload_max_by => ops::By<(i64, i64)>, ops::MaxBy<Second>, |Int(x)| ops::By::from((x, x % 2));
+    workload_min_by => ops::By<(i64, i64)>, ops::MinBy<Second>, |Int(x)| ops::By::from((x, x % 2));
+    workload_top_k_by => ops::Top<(i64, i64)>, ops::TopKBy<Second, 3>, |Int(x)| {
+        ops::Top::from((x, x % 2))
+    };
+    workload_safe_max => Option<i64>, ops::SafeMax, |Int(x)| Some(x);
+    workload_safe_min => Option<i64>, ops::SafeMin, |Int(x)| Some(x)
+}
+
+/// Pushes 2n elements into a fixed-size window of n elements.
+fn test19<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let n = 100;
+    let values = synthesize(2 * n);
+    let mut window = swag::fixed::Fixed::new(Window::new(), n);
+    for (i, v) in values.iter().enumerate() {
+        window.push(*v);
+        assert_eq!(window.len(), std::cmp::min(i + 1, n));
+    }
+    let expected = values[n..].iter().fold(0, |acc, Int(x)| acc + x);
+    assert_eq!(window.query(), Int(expected));
+}
+
+/// Registers an eviction callback on a fixed-size window and checks what it receives.
+fn test20<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    let n = 100;
+    let values = synthesize(2 * n);
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = evicted.clone();
+    let mut window =
+        swag::fixed::Fixed::new(Window::new(), n).on_evict(move |v| sink.borrow_mut().push(v));
+    for v in values.iter() {
+        window.push(*v);
+    }
+    assert_eq!(*evicted.borrow(), values[..n].to_vec());
+}
+
+/// Checks that the deltas reported over a session add up to the change of the aggregate.
+fn test21<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut window = swag::delta::Delta::<Window, Int>::new();
+    let mut total = 0;
+    let mut previous = window.query();
+    for (i, v) in synthesize(1_000).into_iter().enumerate() {
+        window.push(v);
+        if i % 3 == 0 {
+            window.pop();
+        }
+        if i % 7 == 0 {
+            let Int(delta) = window.query_delta();
+            assert_eq!(delta, window.query().0 - previous.0);
+            previous = window.query();
+            total += delta;
+        }
+    }
+    total += window.query_delta().0;
+    assert_eq!(total, window.query().0);
+    assert_eq!(window.query_delta(), Int(0));
+}
+
+/// Snapshots a window, mutates it, and restores it to the snapshot.
+fn test22<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let values = synthesize(1_000);
+    let mut window = swag::checkpoint::Checkpoint::<Window>::new();
+    for v in values[..500].iter() {
+        window.push(*v);
+    }
+    let expected = window.query();
+    let snapshot = window.snapshot();
+    for v in values[500..].iter() {
+        window.push(*v);
+        window.pop();
+        window.pop();
+    }
+    assert_eq!(window.len(), 0);
+    window.restore(snapshot.clone()).unwrap();
+    assert_eq!(window.len(), 500);
+    assert_eq!(window.query(), expected);
+    window.pop();
+    window.restore(snapshot).unwrap();
+    assert_eq!(window.query(), expected);
+}
+
+/// Checks that snapshots can only be restored into the window they were taken from.
+fn test60<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut window = swag::checkpoint::Checkpoint::<Window>::new();
+    let mut other = swag::checkpoint::Checkpoint::<Window>::new();
+    window.push(Int(1));
+    window.push(Int(2));
+    window.pop();
+    assert_eq!(window.version(), 3);
+    let snapshot = window.snapshot();
+    assert_eq!(snapshot.version(), 3);
+    other.push(Int(3));
+    assert_eq!(
+        other.restore(snapshot.clone()),
+        Err(swag::checkpoint::SnapshotError::Foreign)
+    );
+    assert_eq!(other.query(), Int(3));
+    let mut clone = window.clone();
+    window.push(Int(4));
+    assert_eq!(
+        clone.restore(window.snapshot()),
+        Err(swag::checkpoint::SnapshotError::Diverged)
+    );
+    assert_eq!(clone.query(), Int(2));
+    assert_eq!(window.clone().restore(snapshot.clone()), Ok(()));
+    assert_eq!(window.restore(snapshot), Ok(()));
+    assert_eq!(window.query(), Int(2));
+    assert_eq!(window.version(), 5);
+    let mut clone = window.clone();
+    window.push(Int(5));
+    let snapshot = window.snapshot();
+    clone.push(Int(6));
+    clone.push(Int(7));
+    assert!(clone.version() > snapshot.version());
+    assert_eq!(
+        clone.restore(snapshot),
+        Err(swag::checkpoint::SnapshotError::Diverged)
+    );
+    assert_eq!(
+        window.restore(clone.snapshot()),
+        Err(swag::checkpoint::SnapshotError::Diverged)
+    );
+    assert_eq!(clone.query(), Int(15));
+    assert_eq!(window.query(), Int(7));
+}
+
+/// Compares a batch of range queries against individual and brute-force range queries.
+fn test23<Window>()
+where
+    Window: FifoWindow<Int, Sum> + SubWindow<usize, Int>,
+{
+    let mut rng = rand::thread_rng();
+    let values = synthesize(1_000);
+    let mut window = Window::new();
+    for (i, v) in values.iter().enumerate() {
+        window.push(*v);
+        if i % 3 == 0 {
+            window.pop();
+        }
+        if i % 50 == 0 {
+            let offset = i + 1 - window.len();
+            let ranges = (0..20)
+                .map(|_| {
+                    let start = rng.gen_range(0, window.len() + 1);
+                    let end = rng.gen_range(start, window.len() + 1);
+                    start..end
+                })
+                .collect::<Vec<_>>();
+            let aggs = window.query_ranges(&ranges);
+            for (range, agg) in ranges.into_iter().zip(aggs) {
+                let expected = values[offset + range.start..offset + range.end]
+                    .iter()
+                    .fold(0, |acc, Int(x)| acc + x);
+                assert_eq!(agg, Int(expected));
+                assert_eq!(window.range_query(range), agg);
+            }
+            // Empty, reversed, and out of bounds ranges are clamped to the window
+            let len = window.len();
+            let edges = [
+                0..0,
+                len..len,
+                len / 2 + 1..len / 2,
+                len..0,
+                0..len + 10,
+                len + 1..len + 5,
+            ];
+            let aggs = window.query_ranges(&edges);
+            let expected = [Int(0), Int(0), Int(0), Int(0), window.query(), Int(0)];
+            assert_eq!(aggs, expected);
+            for (range, agg) in edges.iter().zip(aggs) {
+                assert_eq!(window.range_query(range.clone()), agg);
+            }
+        }
+    }
+}
+
+/// Compares range queries over a non-commutative operator against brute-force
+/// aggregates, so that values which are combined out of order are detected.
+fn test82<Window>()
+where
+    Window: FifoWindow<ops::LastN<i64, 4>, ops::Concat> + SubWindow<usize, ops::LastN<i64, 4>>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for i in 0..1_000 {
+        window.push(ops::LastN::from(i));
+        reference.push_back(i);
+        if rng.gen_bool(0.3) {
+            window.pop();
+            reference.pop_front();
+        }
+        let start = rng.gen_range(0, reference.len() + 1);
+        let end = rng.gen_range(start, reference.len() + 1);
+        let expected = |range: std::ops::Range<usize>| {
+            let values = reference.range(range).rev().take(4).rev();
+            ops::LastN(values.copied().collect::<Vec<_>>())
+        };
+        assert_eq!(window.range_query(start..end), expected(start..end));
+        assert_eq!(
+            window.query_ranges(&[start..end, 0..start]),
+            vec![expected(start..end), expected(0..start)]
+        );
+    }
+}
+
+/// Binary operator for the lexicographically smallest word, which has no identity.
+#[derive(Copy, Clone)]
+struct Smallest;
+
+impl Operator for Smallest {
+    fn operator_token() -> Smallest {
+        Smallest
+    }
+}
+
+/// A word which does not implement `Identity`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Word(&'static str);
+
+impl AbstractMagma<Smallest> for Word {
+    fn operate(&self, other: &Self) -> Self {
+        std::cmp::min_by_key(*self, *other, |Word(w)| *w)
+    }
+}
+
+impl AbstractSemigroup<Smallest> for Word {}
+
+/// Aggregates a semigroup without identity, where the empty window is `None`.
+fn test24<Window>()
+where
+    Window: FifoWindow<ops::Maybe<Word>, Smallest>,
+{
+    let mut window = Window::new();
+    assert_eq!(window.query(), ops::Maybe(None));
+    window.push(Word("b").into());
+    assert_eq!(window.query(), ops::Maybe(Some(Word("b"))));
+    window.push(Word("a").into());
+    window.push(Word("c").into());
+    assert_eq!(window.query(), ops::Maybe(Some(Word("a"))));
+    window.pop();
+    window.pop();
+    assert_eq!(window.query(), ops::Maybe(Some(Word("c"))));
+    window.pop();
+    assert_eq!(window.query().0, None);
+}
+
+/// Estimates the 99th percentile of a heavy-tailed sample with a t-digest.
+fn test27<Window>()
+where
+    Window: FifoWindow<ops::TDigest, ops::Sum>,
+{
+    let mut rng = StdRng::seed_from_u64(27);
+    // Pareto distributed values with shape 1.5
+    let values = (0..20_000)
+        .map(|_| (1.0 - rng.gen::<f64>()).powf(-1.0 / 1.5))
+        .collect::<Vec<f64>>();
+    let mut window = Window::new();
+    for v in values.iter() {
+        window.push(ops::TDigest::with_value(*v));
+    }
+    for _ in 0..10_000 {
+        window.pop();
+    }
+    let mut sorted = values[10_000..].to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let digest = window.query();
+    assert_eq!(digest.count(), 10_000.0);
+    // The rank error of a t-digest shrinks towards the tails
+    for (q, bound) in [(0.5, 0.005), (0.9, 0.003), (0.99, 0.0015)].iter() {
+        let estimate = digest.quantile(*q).unwrap();
+        let rank = sorted.iter().filter(|x| **x <= estimate).count() as f64 / 10_000.0;
+        assert!((rank - q).abs() < *bound, "q={} rank={}", q, rank);
+    }
+    assert_eq!(digest.quantile(0.0), Some(sorted[0]));
+    assert_eq!(digest.quantile(1.0), Some(sorted[9_999]));
+    // NaN values are ordered after all other values instead of panicking
+    window.push(ops::TDigest::with_value(f64::NAN));
+    assert_eq!(window.query().count(), 10_001.0);
+}
+
+/// Compares the heap window against another algorithm on max and min workloads.
+fn test28<Window>()
+where
+    Window: FifoWindow<i64, ops::Max>,
+{
+    let mut rng = rand::thread_rng();
+    let mut heap = swag::heap::HeapWindow::<i64, ops::Max>::new();
+    let mut min = swag::heap::HeapWindow::<i64, ops::Min>::new();
+    let mut max = swag::heap::HeapWindow::<i64, ops::Max>::new();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for i in 0..10_000 {
+        let v = rng.gen_range(0, 100) - i;
+        heap.push(v);
+        min.push(v);
+        max.push(-v);
+        window.push(v);
+        reference.push_back(v);
+        while rng.gen_bool(0.5) {
+            assert_eq!(heap.pop(), window.pop());
+            assert_eq!(min.pop(), reference.front().copied());
+            assert_eq!(max.pop(), reference.pop_front().map(|v| -v));
+        }
+        assert_eq!(heap.query(), window.query());
+        assert_eq!(min.query(), *reference.iter().min().unwrap_or(&i64::MAX));
+        assert_eq!(
+            max.query(),
+            reference.iter().map(|v| -v).max().unwrap_or(i64::MIN)
+        );
+        // Popped values which never reach the top of the heap are still deleted
+        assert!(min.heap_len() <= 2 * min.len());
+        assert!(max.heap_len() <= 2 * max.len());
+    }
+    while !heap.is_empty() {
+        heap.pop();
+        window.pop();
+        assert_eq!(heap.query(), window.query());
+    }
+    assert_eq!(heap.heap_len(), 0);
+    assert_eq!(heap.query(), i64::MIN);
+}
+
+thread_local! {
+    static LOWERED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A mean which counts how many times it has been lowered.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct CountedMean(ops::Mean);
+
+impl Identity<ops::Sum> for CountedMean {
+    fn identity() -> Self {
+        CountedMean(ops::Mean::identity())
+    }
+}
+
+impl AbstractMagma<ops::Sum> for CountedMean {
+    fn operate(&self, other: &Self) -> Self {
+        CountedMean(self.0.operate(&other.0))
+    }
+}
+
+impl AbstractSemigroup<ops::Sum> for CountedMean {}
+impl AbstractMonoid<ops::Sum> for CountedMean {}
+
+impl Lower<ops::Sum> for CountedMean {
+    type Output = f64;
+    fn lower(&self) -> f64 {
+        LOWERED.with(|n| n.set(n.get() + 1));
+        self.0.lower()
+    }
+}
+
+/// Tests that means are lowered once per query, and not once per combine.
+fn test29<Window>()
+where
+    Window: FifoWindow<CountedMean, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    LOWERED.with(|n| n.set(0));
+    assert!(window.query_lowered().is_nan());
+    for i in 1..=1000 {
+        let v = rng.gen_range(0, 100) as f64;
+        window.push(CountedMean(ops::Mean::from(v)));
+        reference.push_back(v);
+        if reference.len() > 1 && rng.gen_bool(0.3) {
+            window.pop();
+            reference.pop_front();
+        }
+        let mean = reference.iter().sum::<f64>() / reference.len() as f64;
+        assert!((window.query_lowered() - mean).abs() < 1e-9);
+        assert_eq!(LOWERED.with(|n| n.get()), i + 1);
+    }
+}
+
+/// Pushes elements with varying weights and evicts them down to a budget.
+fn test30<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = swag::weighted::WeightedWindow::new(Window::new());
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 100);
+        let weight = rng.gen_range(0, 10);
+        window.push_weighted(Int(v), weight);
+        reference.push_back((v, weight));
+        let budget = rng.gen_range(0, 200);
+        let mut evicted = 0;
+        while reference.iter().map(|(_, w)| w).sum::<u64>() > budget {
+            reference.pop_front();
+            evicted += 1;
+        }
+        assert_eq!(window.evict_to(budget), evicted);
+        assert_eq!(
+            window.weight(),
+            reference.iter().map(|(_, w)| w).sum::<u64>()
+        );
+        assert_eq!(window.len(), reference.len());
+        assert_eq!(window.query(), Int(reference.iter().map(|(v, _)| v).sum()));
+    }
+    window.evict_to(0);
+    for (v, weight) in [(1, 3), (2, 0), (3, 2), (4, 5)].iter() {
+        window.push_weighted(Int(*v), *weight);
+    }
+    let remaining = window.len() - 4;
+    assert_eq!(window.evict_to(5), remaining + 3);
+    assert_eq!(window.query(), Int(4));
+    assert_eq!(window.weight(), 5);
+    window.push(Int(5));
+    assert_eq!(window.weight(), 5);
+    assert_eq!(window.pop_weighted(), Some((Int(4), 5)));
+    assert_eq!(window.pop(), Some(Int(5)));
+    assert_eq!(window.weight(), 0);
+}
+
+/// Checks that a checked sum reports overflow as an error from `try_query`.
+fn test32<Window>()
+where
+    Window: FifoWindow<ops::CheckedSum, ops::Sum>,
+{
+    let mut window = Window::new();
+    window.push(ops::CheckedSum::from(i64::MAX - 1));
+    assert_eq!(
+        window.try_query().map(|sum| sum.get()),
+        Ok(Some(i64::MAX - 1))
+    );
+    window.push(ops::CheckedSum::from(2));
+    assert_eq!(window.try_query(), Err(QueryError::Overflow));
+    window.push(ops::CheckedSum::from(-2));
+    assert_eq!(
+        window.try_query().map(|sum| sum.get()),
+        Ok(Some(i64::MAX - 1))
+    );
+    window.pop();
+    assert_eq!(window.try_query().map(|sum| sum.get()), Ok(Some(0)));
+    window.push(ops::CheckedSum::from(i64::MIN));
+    window.push(ops::CheckedSum::from(-1));
+    assert_eq!(window.try_query(), Err(QueryError::Overflow));
+}
+
+/// Checks that peeking at a push equals pushing, and leaves the window unchanged.
+fn test33<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    for v in synthesize(1000) {
+        let before = window.query();
+        let len = window.len();
+        let peeked = window.peek_query(&v);
+        assert_eq!(window.query(), before);
+        assert_eq!(window.len(), len);
+        window.push(v);
+        assert_eq!(window.query(), peeked);
+        if rng.gen_bool(0.5) {
+            window.pop();
+        }
+    }
+}
+
+/// Compares the geometric mean against a recalculation over the window.
+fn test34<Window>()
+where
+    Window: FifoWindow<ops::GeometricMean, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(0.5, 100.0);
+        window.push(ops::GeometricMean::from(v));
+        reference.push_back(v);
+        if reference.len() > 1 && rng.gen_bool(0.5) {
+            window.pop();
+            reference.pop_front();
+        }
+        let n = reference.len() as f64;
+        let expected = reference.iter().map(|x| x.powf(1.0 / n)).product::<f64>();
+        assert!((window.query_lowered() - expected).abs() < 1e-9 * expected);
+    }
+    window.push(ops::GeometricMean::from(0.0));
+    assert!(window.query_lowered().is_nan());
+    while window.len() > 1 {
+        window.pop();
+    }
+    assert!(window.query_lowered().is_nan());
+    window.push(ops::GeometricMean::from(4.0));
+    window.push(ops::GeometricMean::from(16.0));
+    window.pop();
+    assert!((window.query_lowered() - 8.0).abs() < 1e-9);
+}
+
+/// Compares the harmonic mean against a recalculation over the window.
+fn test35<Window>()
+where
+    Window: FifoWindow<ops::HarmonicMean, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert!(window.query_lowered().is_nan());
+    for _ in 0..1000 {
+        let v = rng.gen_range(0.5, 100.0);
+        window.push(ops::HarmonicMean::from(v));
+        reference.push_back(v);
+        if reference.len() > 1 && rng.gen_bool(0.5) {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.len() as f64 / reference.iter().map(|x| 1.0 / x).sum::<f64>();
+        assert!((window.query_lowered() - expected).abs() < 1e-9 * expected);
+    }
+    window.push(ops::HarmonicMean::from(0.0));
+    assert_eq!(window.query_lowered(), 0.0);
+    while window.len() > 1 {
+        window.pop();
+    }
+    assert_eq!(window.query_lowered(), 0.0);
+    window.push(ops::HarmonicMean::from(1.0));
+    window.push(ops::HarmonicMean::from(3.0));
+    window.pop();
+    assert!((window.query_lowered() - 1.5).abs() < 1e-9);
+}
+
+/// Compares the dot product against a manual dot product over the window.
+fn test36<Window>()
+where
+    Window: FifoWindow<ops::DotProduct, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut xs = std::collections::VecDeque::new();
+    let mut ys = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let (x, y) = (rng.gen_range(-10.0, 10.0), rng.gen_range(-10.0, 10.0));
+        window.push(ops::DotProduct::from((x, y)));
+        xs.push_back(x);
+        ys.push_back(y);
+        if rng.gen_bool(0.5) {
+            window.pop();
+            xs.pop_front();
+            ys.pop_front();
+        }
+        let expected = xs.iter().zip(ys.iter()).map(|(x, y)| x * y).sum::<f64>();
+        assert!((window.query_lowered() - expected).abs() < 1e-6);
+    }
+}
+
+/// Checks the most frequent value of a window through pushes and pops.
+fn test37<Window>()
+where
+    Window: FifoWindow<ops::Mode<i64>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), None);
+    for i in 0..1000 {
+        // Every third value is 7, which dominates the other values
+        let v = if i % 3 == 0 { 7 } else { rng.gen_range(0, 20) };
+        window.push(ops::Mode::from(v));
+        reference.push_back(v);
+        if reference.len() > 30 {
+            window.pop();
+            reference.pop_front();
+        }
+        let mut counts = std::collections::BTreeMap::new();
+        for v in reference.iter() {
+            *counts.entry(*v).or_insert(0) += 1;
+        }
+        let max = counts.values().max().unwrap();
+        let expected = counts.iter().find(|(_, c)| *c == max).map(|(v, _)| *v);
+        assert_eq!(window.query_lowered(), expected);
+    }
+    while !window.is_empty() {
+        window.pop();
+    }
+    for v in [3, 3, 2, 1].iter() {
+        window.push(ops::Mode::from(*v));
+    }
+    assert_eq!(window.query_lowered(), Some(3));
+    window.pop();
+    assert_eq!(window.query_lowered(), Some(1));
+    window.pop();
+    window.pop();
+    assert_eq!(window.query_lowered(), Some(1));
+}
+
+/// Compares the maximum subarray sum against Kadane's algorithm over the window.
+fn test38<Window>()
+where
+    Window: FifoWindow<ops::MaxSubarraySum, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..2000 {
+        let v = rng.gen_range(-100, 100);
+        window.push(ops::MaxSubarraySum::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let (mut current, mut best) = (0, 0);
+        for v in reference.iter() {
+            current = std::cmp::max(current + v, 0);
+            best = std::cmp::max(best, current);
+        }
+        assert_eq!(window.query_lowered(), best);
+    }
+}
+
+/// Compares the weighted sum against a manual weighted sum over the window.
+fn test39<Window>()
+where
+    Window: FifoWindow<ops::WeightedSum, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let (value, weight) = (rng.gen_range(-10.0, 10.0), rng.gen_range(0.0, 1.0));
+        window.push(ops::WeightedSum::from((value, weight)));
+        reference.push_back((value, weight));
+        if rng.gen_bool(0.5) {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().map(|(v, w)| v * w).sum::<f64>();
+        assert!((window.query_lowered() - expected).abs() < 1e-6);
+    }
+}
+
+/// Compares the exact distinct count against the distinct elements of the window.
+fn test40<Window>()
+where
+    Window: FifoWindow<ops::ExactDistinctCount<i64>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 50);
+        window.push(ops::ExactDistinctCount::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .collect::<std::collections::BTreeSet<_>>()
+            .len();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Bounds a window by both count and age, where each bound triggers first once.
+fn test41<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    // The count bound triggers first when elements arrive quickly
+    let mut window = swag::hybrid::HybridWindow::new(Window::new())
+        .max_count(3)
+        .max_age(100_u64);
+    for t in 0..10 {
+        let evicted = window.insert(t, Int(t as i64));
+        assert_eq!(evicted, if t < 3 { 0 } else { 1 });
+    }
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.query(), Int(7 + 8 + 9));
+    assert_eq!(window.oldest(), Some(7));
+    // The age bound triggers first when elements arrive slowly
+    let mut window = swag::hybrid::HybridWindow::new(Window::new())
+        .max_count(100)
+        .max_age(10_u64);
+    for t in (0..100).step_by(4) {
+        window.insert(t, Int(1));
+        assert!(window.len() <= 3);
+    }
+    assert_eq!(window.oldest(), Some(88));
+    assert_eq!(window.query(), Int(3));
+    // Advancing without inserting evicts old elements
+    assert_eq!(window.advance(105), 2);
+    assert_eq!(window.query(), Int(1));
+    assert_eq!(window.advance(1000), 1);
+    assert!(window.is_empty());
+}
+
+/// A level which is backed by a `u16`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Level(u16);
+
+impl num_traits::Bounded for Level {
+    fn min_value() -> Self {
+        Level(u16::MIN)
+    }
+    fn max_value() -> Self {
+        Level(u16::MAX)
+    }
+}
+
+/// Checks that the identity of `Max` is the smallest value of a `u16`-backed type.
+fn test42<Window>()
+where
+    Window: FifoWindow<ops::Ordered<Level>, ops::Max>,
+{
+    let mut window = Window::new();
+    assert_eq!(window.query(), ops::Ordered(Level(u16::MIN)));
+    for v in [3, 9, 1, 4].iter() {
+        window.push(ops::Ordered(Level(*v)));
+    }
+    assert_eq!(window.query(), ops::Ordered(Level(9)));
+    while window.pop().is_some() {}
+    assert_eq!(window.query(), ops::Ordered(Level(u16::MIN)));
+}
+
+/// Checks that the identity of `Min` is the largest value of a `u16`.
+fn test43<Window>()
+where
+    Window: FifoWindow<u16, ops::Min>,
+{
+    let mut window = Window::new();
+    assert_eq!(window.query(), u16::MAX);
+    for v in [3, 9, 1, 4].iter() {
+        window.push(*v);
+    }
+    assert_eq!(window.query(), 1);
+    while window.pop().is_some() {}
+    assert_eq!(window.query(), u16::MAX);
+}
+
+/// Projects tuples onto their second field.
+struct Second;
+
+impl ops::Key<(i64, i64)> for Second {
+    type Key = i64;
+    fn key(v: &(i64, i64)) -> i64 {
+        v.1
+    }
+}
+
+/// Compares the tuple with the largest second field against a scan over the window.
+fn test44<Window>()
+where
+    Window: FifoWindow<ops::By<(i64, i64)>, ops::MaxBy<Second>>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query(), ops::By(None));
+    for i in 0..1000 {
+        let v = (i, rng.gen_range(0, 20));
+        window.push(ops::By::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().copied().max_by_key(|v| v.1);
+        assert_eq!(window.query(), ops::By(expected));
+    }
+}
+
+/// Compares the tuple with the smallest second field against a scan over the window.
+fn test45<Window>()
+where
+    Window: FifoWindow<ops::By<(i64, i64)>, ops::MinBy<Second>>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for i in 0..1000 {
+        let v = (i, rng.gen_range(0, 20));
+        window.push(ops::By::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().copied().min_by_key(|v| v.1);
+        assert_eq!(window.query(), ops::By(expected));
+    }
+}
+
+/// Pushes and pops a zero, and checks that the product recovers afterwards.
+fn test46<Window>()
+where
+    Window: FifoWindow<ops::ZeroCountProduct, ops::Product>,
+{
+    let mut window = Window::new();
+    assert_eq!(window.query_lowered(), 1.0);
+    window.push(ops::ZeroCountProduct::from(0.0));
+    for v in [2.0, 3.0, 0.5, 4.0].iter() {
+        window.push(ops::ZeroCountProduct::from(*v));
+        assert_eq!(window.query_lowered(), 0.0);
+    }
+    window.pop();
+    assert!((window.query_lowered() - 12.0).abs() < 1e-9);
+    window.push(ops::ZeroCountProduct::from(0.0));
+    window.push(ops::ZeroCountProduct::from(-1.0));
+    assert_eq!(window.query_lowered(), 0.0);
+    for _ in 0..4 {
+        window.pop();
+    }
+    assert_eq!(window.query_lowered(), 0.0);
+    window.pop();
+    assert!((window.query_lowered() + 1.0).abs() < 1e-9);
+}
+
+/// Compares the number of values within a band against a filtered count.
+fn test47<Window>()
+where
+    Window: FifoWindow<ops::CountInRange, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let band = 10.0..=20.0;
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(0.0, 30.0);
+        window.push(ops::CountInRange::lift(&v, &band));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().filter(|v| band.contains(*v)).count();
+        assert_eq!(window.query(), ops::CountInRange(expected as i64));
+    }
+    assert_eq!(ops::CountInRange::lift(&10.0, &band), ops::CountInRange(1));
+    assert_eq!(ops::CountInRange::lift(&20.0, &band), ops::CountInRange(1));
+}
+
+/// Checks that the sum and count equal the sum and length of the window.
+fn test48<Window>()
+where
+    Window: FifoWindow<ops::SumCount, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), (0, 0));
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100, 100);
+        window.push(ops::SumCount::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = (reference.iter().sum::<i64>(), reference.len());
+        assert_eq!(window.query_lowered(), expected);
+        assert_eq!(window.query_lowered().1, window.len());
+    }
+}
+
+/// Compares the three tuples with the largest second fields against sorting the window.
+fn test49<Window>()
+where
+    Window: FifoWindow<ops::Top<(i64, i64)>, ops::TopKBy<Second, 3>>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), vec![]);
+    for i in 0..1000 {
+        let v = (i, rng.gen_range(0, 20));
+        window.push(ops::Top::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let mut sorted = reference.iter().copied().collect::<Vec<_>>();
+        sorted.sort_by_key(|&(i, score)| std::cmp::Reverse((score, i)));
+        sorted.truncate(3);
+        assert_eq!(window.query_lowered(), sorted);
+    }
+}
+
+/// Compares the sorted values of the window, with duplicates, against sorting the window.
+fn test50<Window>()
+where
+    Window: FifoWindow<ops::CollectSorted<i64>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), vec![]);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 20);
+        window.push(ops::CollectSorted::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let mut sorted = reference.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        assert_eq!(window.query_lowered(), sorted);
+    }
+}
+
+/// Compares the three most recent values against the back of the window.
+fn test51<Window>()
+where
+    Window: FifoWindow<ops::LastN<i64, 3>, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), vec![]);
+    for i in 0..1000 {
+        window.push(ops::LastN::from(i));
+        reference.push_back(i);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let skip = reference.len().saturating_sub(3);
+        let expected = reference.iter().skip(skip).copied().collect::<Vec<_>>();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Compares the three oldest values against the front of the window.
+fn test52<Window>()
+where
+    Window: FifoWindow<ops::FirstN<i64, 3>, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), vec![]);
+    for i in 0..1000 {
+        window.push(ops::FirstN::from(i));
+        reference.push_back(i);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().take(3).copied().collect::<Vec<_>>();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Compares each field of the summary against independent computations over the window.
+fn test53<Window>()
+where
+    Window: FifoWindow<ops::Gauge, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    let summary = window.query_lowered();
+    assert_eq!(summary.count, 0);
+    assert!(summary.mean.is_nan());
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100, 100) as f64;
+        window.push(ops::Gauge::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let summary = window.query_lowered();
+        let sum = reference.iter().sum::<f64>();
+        assert_eq!(summary.count, reference.len());
+        assert_eq!(summary.sum, sum);
+        assert_eq!(
+            summary.min,
+            reference.iter().copied().fold(f64::INFINITY, f64::min)
+        );
+        assert_eq!(
+            summary.max,
+            reference.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+        );
+        if !reference.is_empty() {
+            assert_eq!(summary.mean, sum / reference.len() as f64);
+        }
+    }
+}
+
+/// Compares the rank of several probe values against a brute-force count over the window.
+fn test54<Window>()
+where
+    Window: FifoWindow<ops::Rank<i64>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query().rank(&0), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 20);
+        window.push(ops::Rank::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let agg = window.query();
+        for probe in -1..=20 {
+            let expected = reference.iter().filter(|v| **v <= probe).count();
+            assert_eq!(agg.rank(&probe), expected);
+        }
+    }
+}
+
+/// Compares the invertible variance against the associative variance after many pops.
+fn test55<Window>()
+where
+    Window: FifoWindow<ops::InvertibleVariance, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = swag::recalc::ReCalc::<ops::Variance, ops::Sum>::new();
+    assert!(window.query_lowered().is_nan());
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100.0, 100.0);
+        window.push(ops::InvertibleVariance::from(v));
+        reference.push(ops::Variance::from(v));
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop();
+        }
+        if !reference.is_empty() {
+            let expected = reference.query_lowered();
+            assert!((window.query_lowered() - expected).abs() < 1e-6 * expected.max(1.0));
+        }
+    }
+}
+
+/// Fills a fixed-size window, shrinks it to half its capacity, and grows and refills it.
+fn test56<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let n = 100;
+    let values = synthesize(2 * n);
+    let sum = |values: &[Int]| Int(values.iter().fold(0, |acc, Int(x)| acc + x));
+    let mut window = swag::fixed::Fixed::new(Window::new(), n);
+    for v in values[..n].iter() {
+        window.push(*v);
+    }
+    assert_eq!(window.set_capacity(n / 2), n / 2);
+    assert_eq!(window.capacity(), n / 2);
+    assert_eq!(window.len(), n / 2);
+    assert_eq!(window.query(), sum(&values[n / 2..n]));
+    assert_eq!(window.set_capacity(n), 0);
+    assert_eq!(window.query(), sum(&values[n / 2..n]));
+    for (i, v) in values[n..].iter().enumerate() {
+        window.push(*v);
+        assert_eq!(window.len(), std::cmp::min(n / 2 + i + 1, n));
+    }
+    assert_eq!(window.query(), sum(&values[n..]));
+}
+
+/// Compares the covariance matrix of three-dimensional values against a batch computation.
+fn test57<Window>()
+where
+    Window: FifoWindow<ops::CovarianceMatrix<3>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let x = rng.gen_range(-10.0, 10.0);
+        let v = [
+            x,
+            2.0 * x + rng.gen_range(-1.0, 1.0),
+            rng.gen_range(0.0, 5.0),
+        ];
+        window.push(ops::CovarianceMatrix::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        if reference.is_empty() {
+            continue;
+        }
+        let n = reference.len() as f64;
+        let mean = |i: usize| reference.iter().map(|v| v[i]).sum::<f64>() / n;
+        let covariance = window.query_lowered();
+        for i in 0..3 {
+            for j in 0..3 {
+                let (mean_i, mean_j) = (mean(i), mean(j));
+                let expected = reference
+                    .iter()
+                    .map(|v| (v[i] - mean_i) * (v[j] - mean_j))
+                    .sum::<f64>()
+                    / n;
+                assert!((covariance[i][j] - expected).abs() < 1e-9 * expected.abs().max(1.0));
+            }
+        }
+    }
+}
+
+/// Slides a full window, and compares it against a window which pushes and pops separately.
+fn test58<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let n = 100;
+    let values = synthesize(10 * n);
+    let mut window = Window::new();
+    let mut reference = Window::new();
+    assert_eq!(window.slide(Int(1)), Int(1));
+    assert!(window.is_empty());
+    for v in values[..n].iter() {
+        window.push(*v);
+        reference.push(*v);
+    }
+    for v in values[n..].iter() {
+        window.slide(*v);
+        reference.push(*v);
+        reference.pop();
+        assert_eq!(window.len(), n);
+        assert_eq!(window.query(), reference.query());
+    }
+}
+
+/// Maps a sum of cents into dollars, and compares it against mapping the plain query.
+fn test59<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let cents = |Int(s): Int| s as f64 / 100.0;
+    let mut window = swag::output::OutputMap::new(Window::new(), cents);
+    let mut reference = Window::new();
+    for (i, v) in synthesize(1_000).into_iter().enumerate() {
+        window.push(v);
+        reference.push(v);
+        if i % 3 == 0 {
+            window.pop();
+            reference.pop();
+        }
+        assert_eq!(window.query(), cents(reference.query()));
+    }
+}
+
+/// Inserts windows with the same values but different internal states into a set.
+fn test61<Window>()
+where
+    Window: FifoWindow<i64, ops::Sum> + std::hash::Hash + Eq,
+{
+    let mut set = std::collections::HashSet::new();
+    let mut a = Window::new();
+    let mut b = Window::new();
+    for v in 0..100 {
+        a.push(v);
+    }
+    for v in 0..50 {
+        b.push(v);
+        b.query();
+    }
+    for v in 50..100 {
+        b.push(v);
+        b.pop();
+    }
+    for _ in 0..50 {
+        a.pop();
+    }
+    assert!(a == b);
+    set.insert(a.clone());
+    set.insert(b);
+    assert_eq!(set.len(), 1);
+    a.pop();
+    assert!(!set.contains(&a));
+    set.insert(a);
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(&Window::new()));
+}
+
+/// Pushes n values, pops k values, and checks the positions of the oldest and newest values.
+fn test62<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let (n, k) = (100, 30);
+    let mut window = swag::span::Spanned::<Window>::new();
+    assert_eq!(window.span(), None);
+    for v in synthesize(n) {
+        window.push(v);
+    }
+    for _ in 0..k {
+        window.pop();
+    }
+    assert_eq!(window.span(), Some(k..=n - 1));
+    while window.pop().is_some() {}
+    window.pop();
+    assert_eq!(window.span(), None);
+    window.push(Int(1));
+    assert_eq!(window.span(), Some(n..=n));
+}
+
+/// Compares the total variation against a brute-force sum over consecutive values.
+fn test63<Window>()
+where
+    Window: FifoWindow<ops::TotalVariation, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100, 100);
+        window.push(ops::TotalVariation::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .zip(reference.iter().skip(1))
+            .map(|(a, b)| (b - a).abs())
+            .sum::<i64>();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Compares the decayed count at several query times against a direct computation.
+fn test64<Window>()
+where
+    Window: FifoWindow<ops::DecayedCount, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let lambda = 0.1;
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    let mut t = 0.0;
+    assert_eq!(window.query().at(t), 0.0);
+    for _ in 0..1000 {
+        t += rng.gen_range(0.0, 2.0);
+        window.push(ops::DecayedCount::new(t, lambda));
+        reference.push_back(t);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        for now in [t, t + 1.0, t + 10.0].iter() {
+            let expected = reference
+                .iter()
+                .map(|t| (-lambda * (now - t)).exp())
+                .sum::<f64>();
+            assert!((window.query().at(*now) - expected).abs() < 1e-9);
+        }
+    }
+}
+
+/// Compares the longest increasing run against a brute-force scan over the values.
+fn test65<Window>()
+where
+    Window: FifoWindow<ops::LongestRun, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 10);
+        window.push(ops::LongestRun::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let mut expected = 0;
+        let mut run = 0;
+        let mut prev = None;
+        for v in reference.iter() {
+            run = match prev {
+                Some(p) if p < v => run + 1,
+                _ => 1,
+            };
+            expected = expected.max(run);
+            prev = Some(v);
+        }
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Checks that pushing slices at once gives the same aggregate as pushing their
+/// values one by one.
+fn test66<Window>()
+where
+    Window: FifoWindow<ops::LastN<i64, 64>, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut bulk = Window::new();
+    let mut single = Window::new();
+    for _ in 0..100 {
+        let slice = (0..rng.gen_range(0, 20))
+            .map(|_| ops::LastN::from(rng.gen_range(-100, 100)))
+            .collect::<Vec<_>>();
+        bulk.push_all(&slice);
+        for v in slice {
+            single.push(v);
+        }
+        while rng.gen_bool(0.5) && !single.is_empty() {
+            bulk.pop();
+            single.pop();
+        }
+        assert_eq!(bulk.len(), single.len());
+        assert_eq!(bulk.query(), single.query());
+    }
+}
+
+/// Compares the bundled means against independent computations of each mean.
+fn test67<Window>()
+where
+    Window: FifoWindow<ops::MeanBundle, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert!(window.query_lowered().arithmetic.is_nan());
+    for _ in 0..1000 {
+        let v = rng.gen_range(0.1, 100.0);
+        window.push(ops::MeanBundle::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        if !reference.is_empty() {
+            let n = reference.len() as f64;
+            let arithmetic = reference.iter().sum::<f64>() / n;
+            let geometric = reference.iter().map(|v| v.powf(1.0 / n)).product::<f64>();
+            let harmonic = n / reference.iter().map(|v| 1.0 / v).sum::<f64>();
+            let means = window.query_lowered();
+            assert!((means.arithmetic - arithmetic).abs() < 1e-6 * arithmetic);
+            assert!((means.geometric - geometric).abs() < 1e-6 * geometric);
+            assert!((means.harmonic - harmonic).abs() < 1e-6 * harmonic);
+        }
+    }
+}
+
+/// Checks that the earliest value above the threshold is reported after pushes and pops.
+fn test68<Window>()
+where
+    Window: FifoWindow<ops::FirstAbove<i64>, ops::Min>,
+{
+    let mut rng = rand::thread_rng();
+    let threshold = 90;
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), None);
+    for i in 0..1000 {
+        let v = rng.gen_range(0, 100);
+        window.push(ops::FirstAbove::lift(i, v, &threshold));
+        reference.push_back((i, v));
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference.iter().find(|(_, v)| *v > threshold).cloned();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Checks that lowering into a buffer overwrites it with the same output as lowering
+/// into a new value, and that the buffer is reused across queries.
+fn test69<Window>()
+where
+    Window: FifoWindow<ops::LastN<i64, 16>, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut buf = vec![i64::MAX; 16];
+    let ptr = buf.as_ptr();
+    for _ in 0..1000 {
+        window.push(ops::LastN::from(rng.gen_range(-100, 100)));
+        while rng.gen_bool(0.5) && !window.is_empty() {
+            window.pop();
+        }
+        window.query_into(&mut buf);
+        assert_eq!(buf, window.query_lowered());
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+}
+
+/// Compares an operation composed with the builder against a manual fold.
+fn test70<Window>()
+where
+    Window: FifoWindow<i64, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = swag::operation::op()
+        .filter(|x: &i64| x % 2 == 0)
+        .map(|x| x * x)
+        .sum()
+        .window(Window::new());
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100, 100);
+        window.push(v);
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .filter(|x| *x % 2 == 0)
+            .fold(0, |acc, x| acc + x * x);
+        assert_eq!(window.query(), expected);
+    }
+}
+
+/// Pushes 250 values in buckets of 100, and checks that two complete buckets are drained.
+fn test71<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let values = synthesize(250);
+    let sum = |values: &[Int]| Int(values.iter().fold(0, |acc, Int(x)| acc + x));
+    let mut window = swag::tumbling::TumblingSlidingWindow::new(Window::new(), 100);
+    for v in values[..150].iter() {
+        window.push(*v);
+    }
+    assert_eq!(window.drain_tumbled(), vec![sum(&values[..100])]);
+    for v in values[150..].iter() {
+        window.push(*v);
+        window.pop();
+    }
+    assert_eq!(window.drain_tumbled(), vec![sum(&values[100..200])]);
+    assert_eq!(window.drain_tumbled(), vec![]);
+    assert_eq!(window.bucket_len(), 50);
+    assert_eq!(window.len(), 150);
+    assert_eq!(window.query(), sum(&values[100..]));
+}
+
+/// Checks that the difference of two sum windows is the difference of their sums.
+fn test72<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut a = Window::new();
+    let mut b = Window::new();
+    assert_eq!(a.diff(&b), Int(0));
+    for _ in 0..1000 {
+        a.push(Int(rng.gen_range(-100, 100)));
+        b.push(Int(rng.gen_range(-100, 100)));
+        if rng.gen_bool(0.5) {
+            a.pop();
+        }
+        if rng.gen_bool(0.5) {
+            b.pop();
+        }
+        let (Int(x), Int(y)) = (a.query(), b.query());
+        assert_eq!(a.diff(&b), Int(x - y));
+        assert_eq!(b.diff(&a), Int(y - x));
+    }
+}
+
+/// Compares the number of peaks against a brute-force scan over neighboring values.
+fn test73<Window>()
+where
+    Window: FifoWindow<ops::PeakCount, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 10);
+        window.push(ops::PeakCount::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let values = reference.iter().collect::<Vec<_>>();
+        let expected = values
+            .windows(3)
+            .filter(|w| w[0] < w[1] && w[1] > w[2])
+            .count();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// Checks that a window containing `i64::MIN` is only reported as empty once it is.
+fn test74<Window>()
+where
+    Window: FifoWindow<Option<i64>, ops::SafeMax>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    assert_eq!(window.query(), None);
+    window.push(Some(i64::MIN));
+    assert_eq!(window.query(), Some(i64::MIN));
+    window.pop();
+    assert_eq!(window.query(), None);
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = i64::MIN + rng.gen_range(0, 10);
+        window.push(Some(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        assert_eq!(window.query(), reference.iter().max().cloned());
+    }
+}
+
+/// Compares the rolling hash against a hash computed from scratch, and checks that
+/// windows with identical contents have identical hashes.
+fn test75<Window>()
+where
+    Window: FifoWindow<ops::RollingHash, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let hash = |values: &[u64]| {
+        let modulus = (1u128 << 61) - 1;
+        values
+            .iter()
+            .fold(0, |acc, v| (acc * 0x5bd1_e995 + *v as u128) % modulus) as u64
+    };
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 1000);
+        window.push(ops::RollingHash::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let values = reference.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(window.query_lowered(), hash(&values));
+        let mut copy = Window::new();
+        for v in values.iter() {
+            copy.push(ops::RollingHash::from(*v));
+        }
+        assert_eq!(copy.query(), window.query());
+    }
+}
+
+/// Compares the number of crossings of a fixed level against a brute-force scan over
+/// consecutive values.
+fn test76<Window>()
+where
+    Window: FifoWindow<ops::CrossingRate, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let level = 0.5;
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query_lowered(), 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(0.0, 1.0);
+        window.push(ops::CrossingRate::lift(&v, &level));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .zip(reference.iter().skip(1))
+            .filter(|(a, b)| (**a < level) != (**b < level))
+            .count();
+        assert_eq!(window.query_lowered(), expected);
+    }
+}
+
+/// The number of counters in each row of the Count-Min sketch of `test77`.
+const COUNT_MIN_WIDTH: usize = 16;
+
+/// Checks that the Count-Min sketch never under-counts the values of the window.
+fn test77<Window>()
+where
+    Window: FifoWindow<ops::CountMin<4, COUNT_MIN_WIDTH>, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(0, 100);
+        window.push(ops::CountMin::lift(&v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        let sketch = window.query();
+        for v in 0..100 {
+            let count = reference.iter().filter(|x| **x == v).count() as u64;
+            assert!(sketch.estimate(&v) >= count);
+        }
+        // Every value is counted exactly once in each row, e.g., the first one
+        let first_row = sketch.counters.iter().take(COUNT_MIN_WIDTH);
+        assert_eq!(first_row.sum::<u64>(), reference.len() as u64);
+    }
+}
+
+/// An accumulator which sums the aggregates of windows.
+struct Total(i64);
+
+impl Accumulator<Int> for Total {
+    fn accumulate(&mut self, Int(x): Int) {
+        self.0 += x;
+    }
+}
+
+/// Folds the aggregates of several windows into an accumulator and checks the total.
+fn test78<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let values = synthesize(100);
+    let sum = |values: &[Int]| values.iter().fold(0, |acc, Int(x)| acc + x);
+    let mut total = Total(0);
+    for chunk in values.chunks(30) {
+        let mut window = Window::new();
+        for v in chunk {
+            window.push(*v);
+        }
+        window.fold_into(&mut total);
+    }
+    assert_eq!(total.0, sum(&values));
+}
+
+/// Compares the lag-3 autocorrelation against a brute-force computation.
+fn test79<Window>()
+where
+    Window: FifoWindow<ops::AutoCorr<3>, ops::Concat>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert!(window.query_lowered().is_nan());
+    for _ in 0..1000 {
+        let v = rng.gen_range(-10.0, 10.0);
+        window.push(ops::AutoCorr::from(v));
+        reference.push_back(v);
+        while rng.gen_bool(0.5) && !reference.is_empty() {
+            window.pop();
+            reference.pop_front();
+        }
+        if reference.len() > 3 {
+            let n = reference.len() as f64;
+            let mean = reference.iter().sum::<f64>() / n;
+            let covariance = reference
+                .iter()
+                .zip(reference.iter().skip(3))
+                .map(|(a, b)| (a - mean) * (b - mean))
+                .sum::<f64>();
+            let variance = reference.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+            let expected = covariance / variance;
+            assert!((window.query_lowered() - expected).abs() < 1e-6);
+        } else {
+            assert!(window.query_lowered().is_nan());
+        }
+    }
+}
+
+/// Compares the estimated interquartile range of 10k samples against the exact one.
+fn test80<Window>()
+where
+    Window: FifoWindow<ops::InterquartileRange, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    assert!(window.query_lowered().is_nan());
+    let values = (0..10_000)
+        .map(|_| rng.gen_range(-100.0, 100.0))
+        .collect::<Vec<f64>>();
+    for v in values.iter() {
+        window.push(ops::InterquartileRange::from(*v));
+    }
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    // Each quartile is estimated within a rank error of 1%
+    let at = |q: f64| sorted[(q * 9_999.0).round() as usize];
+    let estimate = window.query_lowered();
+    assert!(estimate >= at(0.74) - at(0.26), "estimate={}", estimate);
+    assert!(estimate <= at(0.76) - at(0.24), "estimate={}", estimate);
+}
+
+/// Checks that pushing and querying at once is the same as pushing and then querying.
+fn test81<Window>()
+where
+    Window: FifoWindow<i64, ops::Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Window::new();
+    let mut reference = Window::new();
+    for _ in 0..1000 {
+        let v = rng.gen_range(-100, 100);
+        reference.push(v);
+        let agg = window.push_query(v);
+        assert_eq!(agg, reference.query());
+        assert_eq!(window.query(), agg);
+        while rng.gen_bool(0.5) {
+            window.pop();
+            reference.pop();
+        }
+        if rng.gen_bool(0.5) {
+            assert_eq!(window.query(), reference.query());
+        }
+    }
+}
+
+/// Checks that a value which derives `Fallible` with `infallible!` never reports an error.
+fn test83<Window>()
+where
+    Window: FifoWindow<Int, Sum>,
+{
+    let mut window = Window::new();
+    assert_eq!(window.try_query(), Ok(Int(0)));
+    for i in 0..10 {
+        window.push(Int(i));
+        assert_eq!(window.try_query(), Ok(window.query()));
+    }
+    window.pop();
+    assert_eq!(window.try_query(), Ok(Int(45)));
+}
+
+test_matrix! {
+    test1 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test2 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test3 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test4 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test5 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test6 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test7 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test8 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test9 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test10 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test12 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test13 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test14 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test15 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test19 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test20 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test21 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test22 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test23 => [ recalc::ReCalc,           reactive::Reactive, segtree::SegTree ],
+    test82 => [ recalc::ReCalc,           reactive::Reactive, segtree::SegTree ],
+    test24 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test27 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test28 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test29 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test30 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test32 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test33 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test34 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test35 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test36 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test37 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test38 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test39 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test40 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test41 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test42 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test43 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test44 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test45 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test46 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test47 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test48 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test49 => [ recalc::ReCalc,            reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test50 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test51 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test52 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test53 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test54 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test55 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test56 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test57 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test58 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test59 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test60 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test61 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test62 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test63 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test64 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test65 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test66 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test67 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test68 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test69 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test70 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test71 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test72 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test73 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test74 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test75 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test76 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test77 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test78 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test79 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test80 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test81 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test83 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ]
+}
+
+#[cfg(feature = "testing")]
+test_matrix! {
+    test16 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    test17 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_sum => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_product => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_concat => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_max => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_min => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_max_by => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_min_by => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_top_k_by => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_safe_max => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ],
+    workload_safe_min => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ]
+}
+
+#[cfg(feature = "rust_decimal")]
 test_matrix! {
-    test1 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test2 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test3 => [ recalc::ReCalc,           reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test4 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test5 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test6 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test7 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test8 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ],
-    test9 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT ]
+    test11 => [ recalc::ReCalc, soe::SoE, reactive::Reactive, two_stacks::TwoStacks, flatfit::FlatFIT, adaptive::Adaptive, segtree::SegTree ]
 }
@@ -0,0 +1,62 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use std::collections::VecDeque;
+use swag::flatfit::FlatFIT;
+use swag::*;
+
+/// Binary operator for concatenating two consecutive segments of the window.
+/// Has the following properties:
+/// * Associativity
+#[derive(Copy, Clone)]
+struct Concat;
+
+impl Operator for Concat {
+    fn operator_token() -> Concat {
+        Concat
+    }
+}
+
+/// A sequence of values, which reveals the order in which they were combined.
+#[derive(Clone, PartialEq, Debug)]
+struct Seq(Vec<i64>);
+
+impl Identity<Concat> for Seq {
+    fn identity() -> Seq {
+        Seq(Vec::new())
+    }
+}
+
+impl AbstractMagma<Concat> for Seq {
+    fn operate(&self, other: &Self) -> Self {
+        Seq(self.0.iter().chain(other.0.iter()).cloned().collect())
+    }
+}
+
+impl AbstractSemigroup<Concat> for Seq {}
+impl AbstractMonoid<Concat> for Seq {}
+
+/// Queries combine the values in fifo order, also after the window has wrapped
+/// around and been rescaled.
+#[test]
+fn fifo_order() {
+    let mut window = FlatFIT::<Seq, Concat>::new();
+    let mut reference = VecDeque::new();
+    for i in 0..200 {
+        window.push(Seq(vec![i]));
+        reference.push_back(i);
+        if i % 3 == 0 {
+            window.pop();
+            reference.pop_front();
+        }
+        if i % 50 == 49 {
+            while reference.len() > 2 {
+                window.pop();
+                reference.pop_front();
+            }
+        }
+        assert_eq!(window.query(), Seq(reference.iter().cloned().collect()));
+    }
+}
@@ -0,0 +1,52 @@
+use swag::ops::Concat;
+use swag::ops::LastN;
+use swag::*;
+
+/// Macro for freezing windows of different algorithms.
+macro_rules! test_freeze {
+    { $($name:ident => $module:ident::$algorithm:ident),* } => {
+        $(
+            #[test]
+            fn $name() {
+                let mut window = swag::$module::$algorithm::<LastN<i64, 8>, Concat>::new();
+                for i in 0..20 {
+                    window.push(LastN::from(i));
+                }
+                for _ in 0..5 {
+                    window.pop();
+                }
+                let expected = window.query();
+                let frozen = window.freeze();
+                let clones = (0..4).map(|_| frozen.clone()).collect::<Vec<_>>();
+                for clone in clones.iter() {
+                    assert_eq!(clone.query(), expected);
+                    assert_eq!(clone.len(), 15);
+                    assert_eq!(clone.range_query(0..3), LastN(vec![5, 6, 7]));
+                    assert!(clone.iter().cloned().eq((5..20).map(LastN::from)));
+                }
+            }
+        )*
+    }
+}
+
+test_freeze! {
+    recalc => recalc::ReCalc,
+    reactive => reactive::Reactive,
+    two_stacks => two_stacks::TwoStacks,
+    flatfit => flatfit::FlatFIT,
+    adaptive => adaptive::Adaptive,
+    segtree => segtree::SegTree
+}
+
+#[test]
+fn soe() {
+    let mut window = swag::soe::SoE::<i64, ops::Sum>::new();
+    for i in 0..20 {
+        window.push(i);
+    }
+    let frozen = window.freeze();
+    let clone = frozen.clone();
+    assert_eq!(frozen.query(), 190);
+    assert_eq!(clone.query(), 190);
+    assert_eq!(clone.range_query(10..20), 145);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "inspect")]
+
+use rand::Rng;
+use swag::ops::Concat;
+use swag::ops::LastN;
+use swag::*;
+
+/// Macro for checking the invariants of different algorithms after every operation
+/// of a random workload.
+macro_rules! test_invariants {
+    { $($name:ident => $new:expr, $lift:expr),* } => {
+        $(
+            #[test]
+            fn $name() {
+                let mut rng = rand::thread_rng();
+                let mut window = $new;
+                window.check_invariants().unwrap();
+                for i in 0..2000 {
+                    if rng.gen_bool(0.6) {
+                        window.push($lift(i));
+                    } else {
+                        window.pop();
+                    }
+                    window.check_invariants().unwrap();
+                    window.query();
+                    window.check_invariants().unwrap();
+                }
+            }
+        )*
+    }
+}
+
+test_invariants! {
+    recalc => recalc::ReCalc::<LastN<i64, 4>, Concat>::new(), LastN::from,
+    eager_recalc => recalc::ReCalc::<LastN<i64, 4>, Concat>::builder().lazy(false).build(), LastN::from,
+    soe => soe::SoE::<i64, ops::Sum>::new(), |i| i,
+    reactive => reactive::Reactive::<LastN<i64, 4>, Concat>::new(), LastN::from,
+    two_stacks => two_stacks::TwoStacks::<LastN<i64, 4>, Concat>::new(), LastN::from,
+    flatfit => flatfit::FlatFIT::<LastN<i64, 4>, Concat>::new(), LastN::from,
+    adaptive => adaptive::Adaptive::<LastN<i64, 4>, Concat>::new(), LastN::from,
+    segtree => segtree::SegTree::<LastN<i64, 4>, Concat>::new(), LastN::from
+}
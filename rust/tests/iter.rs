@@ -0,0 +1,27 @@
+use swag::iter::SlidingAggregateExt;
+use swag::ops::Sum;
+use swag::two_stacks::TwoStacks;
+use swag::*;
+
+#[test]
+fn sliding_aggregate() {
+    let aggs = (0..10_i64)
+        .sliding_aggregate(TwoStacks::<i64, Sum>::new(), 3)
+        .collect::<Vec<_>>();
+    assert_eq!(aggs, vec![0, 1, 3, 6, 9, 12, 15, 18, 21, 24]);
+}
+
+#[test]
+fn sliding_aggregate_stride() {
+    let size = 25;
+    let aggs = (0..100_i64)
+        .sliding_aggregate(TwoStacks::<i64, Sum>::new(), size)
+        .stride(10)
+        .collect::<Vec<_>>();
+    assert_eq!(aggs.len(), 10);
+    for (i, agg) in aggs.into_iter().enumerate() {
+        let end = (i as i64 + 1) * 10;
+        let start = (end - size as i64).max(0);
+        assert_eq!(agg, (start..end).sum::<i64>());
+    }
+}
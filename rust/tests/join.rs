@@ -0,0 +1,38 @@
+use rand::Rng;
+use swag::join::JoinWindow;
+use swag::ops::Sum;
+
+/// Inserts interleaved left and right events, and compares the joined sums of products
+/// against a manual pairing of the most recent events.
+#[test]
+fn join_interleaved() {
+    let mut rng = rand::thread_rng();
+    let capacity = 10;
+    let mut join = JoinWindow::<_, _, _, _, Sum>::new(capacity, |l: &i64, r: &i64| l * r);
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for _ in 0..1000 {
+        let k = rng.gen_range(0, 4);
+        let v = rng.gen_range(-100, 100);
+        if rng.gen_bool(0.5) {
+            let recent = &right[right.len().saturating_sub(capacity)..];
+            let expected = recent
+                .iter()
+                .filter(|(key, _)| *key == k)
+                .map(|(_, r)| v * r)
+                .sum::<i64>();
+            assert_eq!(join.insert_left(k, v), expected);
+            left.push((k, v));
+        } else {
+            let recent = &left[left.len().saturating_sub(capacity)..];
+            let expected = recent
+                .iter()
+                .filter(|(key, _)| *key == k)
+                .map(|(_, l)| l * v)
+                .sum::<i64>();
+            assert_eq!(join.insert_right(k, v), expected);
+            right.push((k, v));
+        }
+    }
+    assert_eq!(join.len(), (capacity, capacity));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "laws")]
+
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use swag::laws::Lawful;
+use swag::ops::Sum;
+use swag::segtree::SegTree;
+use swag::two_stacks::TwoStacks;
+use swag::*;
+
+/// A sum which saturates at ±10, and is therefore not associative.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Clamped(i64);
+
+impl Identity<Sum> for Clamped {
+    fn identity() -> Self {
+        Clamped(0)
+    }
+}
+
+impl AbstractMagma<Sum> for Clamped {
+    fn operate(&self, other: &Self) -> Self {
+        Clamped((self.0 + other.0).clamp(-10, 10))
+    }
+}
+
+impl AbstractSemigroup<Sum> for Clamped {}
+impl AbstractMonoid<Sum> for Clamped {}
+
+#[test]
+#[should_panic(expected = "operation is not associative")]
+fn non_associative() {
+    let mut window = Lawful::<TwoStacks<Clamped, Sum>, Clamped>::new();
+    for v in [8, 8, -8].iter().cycle().take(1000) {
+        window.push(Clamped(*v));
+    }
+}
+
+#[test]
+fn associative() {
+    let mut window = Lawful::<SegTree<i64, Sum>, i64>::new();
+    for v in 0..1000 {
+        window.push(v);
+        if v % 3 == 0 {
+            window.pop();
+        }
+    }
+    assert_eq!(window.query(), (0..1000).skip(334).sum());
+}
@@ -0,0 +1,49 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use swag::median::MedianWindow;
+
+/// Compares the medians against a sort of the window after every push and pop.
+#[test]
+fn sorted_median() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut window = MedianWindow::new();
+    let mut reference = std::collections::VecDeque::new();
+    assert_eq!(window.query(), None);
+    for _ in 0..5000 {
+        if rng.gen_bool(0.55) {
+            let v = rng.gen_range(0, 50);
+            window.push(v);
+            reference.push_back(v);
+        } else {
+            assert_eq!(window.pop(), reference.pop_front());
+        }
+        let mut sorted = reference.iter().copied().collect::<Vec<i64>>();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        if n == 0 {
+            assert_eq!(window.medians(), None);
+        } else {
+            assert_eq!(window.query(), Some(sorted[(n - 1) / 2]));
+            assert_eq!(window.medians(), Some((sorted[(n - 1) / 2], sorted[n / 2])));
+        }
+        assert_eq!(window.len(), n);
+    }
+}
+
+/// Slides a fixed-size window over an increasing sequence, so that every pop
+/// evicts from the lower half.
+#[test]
+fn sliding_median() {
+    let mut window = MedianWindow::new();
+    for i in 0..1000 {
+        window.push(i);
+        if window.len() > 5 {
+            window.pop();
+        }
+        let start = i - std::cmp::min(i, 4);
+        assert_eq!(window.query(), Some(start + (i - start) / 2));
+        // Evicted values at the bottom of the lower half are still deleted
+        assert!(window.heap_len() <= 2 * window.len());
+    }
+}
@@ -0,0 +1,32 @@
+use rand::Rng;
+use swag::flatfit::FlatFIT;
+use swag::ops::Concat;
+use swag::ops::LastN;
+use swag::two_stacks::TwoStacks;
+use swag::*;
+
+#[test]
+fn merge_windows_in_order() {
+    let mut rng = rand::thread_rng();
+    let mut older = FlatFIT::<LastN<i64, 8>, Concat>::new();
+    let mut newer = TwoStacks::<LastN<i64, 8>, Concat>::new();
+    let mut combined = std::collections::VecDeque::new();
+    for i in 0..1000 {
+        // Values move from the newer window into the older window, like in a pipeline.
+        newer.push(LastN::from(i));
+        combined.push_back(i);
+        if rng.gen_bool(0.5) {
+            if let Some(v) = newer.pop() {
+                older.push(v);
+            }
+        }
+        if rng.gen_bool(0.3) && older.pop().is_some() {
+            combined.pop_front();
+        }
+        let mut single = TwoStacks::<LastN<i64, 8>, Concat>::new();
+        for v in combined.iter() {
+            single.push(LastN::from(*v));
+        }
+        assert_eq!(merge_windows(&older, &newer), single.query());
+    }
+}
@@ -0,0 +1,50 @@
+use rand::Rng;
+use swag::metered::Metered;
+use swag::metered::Stats;
+use swag::ops::Sum;
+use swag::*;
+
+/// Drives a metered `Window` and checks that it counts the issued operations.
+fn count<Window>()
+where
+    Window: FifoWindow<i64, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut window = Metered::<Window>::new();
+    let mut reference = Window::new();
+    let (mut pushes, mut pops, mut queries) = (0, 0, 0);
+    for _ in 0..1000 {
+        let v = rng.gen_range(1, 5);
+        window.push(v);
+        reference.push(v);
+        pushes += 1;
+        if rng.gen_bool(0.5) {
+            assert_eq!(window.pop(), reference.pop());
+            pops += 1;
+        }
+        if rng.gen_bool(0.5) {
+            assert_eq!(window.query(), reference.query());
+            queries += 1;
+        }
+    }
+    let stats = window.stats();
+    assert_eq!(stats.push.count, pushes);
+    assert_eq!(stats.pop.count, pops);
+    assert_eq!(stats.query.count, queries);
+    assert!(stats.push.min_nanos <= stats.push.mean_nanos());
+    assert!(stats.push.mean_nanos() <= stats.push.max_nanos);
+    window.reset();
+    assert_eq!(window.stats(), Stats::default());
+}
+
+/// Counts the operations issued to every algorithm.
+#[test]
+fn counts() {
+    count::<recalc::ReCalc<i64, Sum>>();
+    count::<soe::SoE<i64, Sum>>();
+    count::<reactive::Reactive<i64, Sum>>();
+    count::<two_stacks::TwoStacks<i64, Sum>>();
+    count::<flatfit::FlatFIT<i64, Sum>>();
+    count::<adaptive::Adaptive<i64, Sum>>();
+    count::<segtree::SegTree<i64, Sum>>();
+}
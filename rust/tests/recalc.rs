@@ -0,0 +1,154 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use std::cell::Cell;
+use swag::ops::Any;
+use swag::ops::Max;
+use swag::ops::Sum;
+use swag::recalc::ReCalc;
+use swag::*;
+
+thread_local! {
+    static OPERATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A sum which counts how many times it has been combined.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Counted(i64);
+
+impl Identity<Sum> for Counted {
+    fn identity() -> Self {
+        Counted(0)
+    }
+}
+
+impl AbstractMagma<Sum> for Counted {
+    fn operate(&self, other: &Self) -> Self {
+        OPERATIONS.with(|n| n.set(n.get() + 1));
+        Counted(self.0 + other.0)
+    }
+}
+
+impl AbstractSemigroup<Sum> for Counted {}
+impl AbstractMonoid<Sum> for Counted {}
+
+/// A logical or which counts how many times it has been combined.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct CountedAny(Any);
+
+impl Identity<Max> for CountedAny {
+    fn identity() -> Self {
+        CountedAny(Any::identity())
+    }
+}
+
+impl AbstractMagma<Max> for CountedAny {
+    fn operate(&self, other: &Self) -> Self {
+        OPERATIONS.with(|n| n.set(n.get() + 1));
+        CountedAny(self.0.operate(&other.0))
+    }
+}
+
+impl AbstractSemigroup<Max> for CountedAny {}
+impl AbstractMonoid<Max> for CountedAny {}
+
+impl Absorbing<Max> for CountedAny {
+    fn is_absorbing(&self) -> bool {
+        self.0.is_absorbing()
+    }
+}
+
+fn operations() -> usize {
+    OPERATIONS.with(|n| n.replace(0))
+}
+
+#[test]
+fn cached_query() {
+    let mut window = ReCalc::<Counted, Sum>::new();
+    for i in 0..100 {
+        window.push(Counted(i));
+    }
+    operations();
+    for _ in 0..1000 {
+        assert_eq!(window.query(), Counted(4950));
+    }
+    assert_eq!(operations(), 100);
+    window.pop();
+    assert_eq!(window.query(), Counted(4950));
+    assert_eq!(window.query(), Counted(4950));
+    assert_eq!(operations(), 99);
+    window.push(Counted(100));
+    let clone = window.clone();
+    assert_eq!(window.query(), Counted(5050));
+    assert_eq!(operations(), 100);
+    assert_eq!(clone.query(), Counted(5050));
+    assert_eq!(operations(), 100);
+}
+
+#[test]
+fn lazy_recalc() {
+    let mut lazy = ReCalc::<Counted, Sum>::new();
+    let mut eager = ReCalc::<Counted, Sum>::builder().lazy(false).build();
+    for round in 0..10 {
+        for i in 0..100 {
+            lazy.push(Counted(i));
+        }
+        operations();
+        assert_eq!(lazy.query(), Counted(4950 * (round + 1)));
+        assert_eq!(operations(), 100 * (round as usize + 1));
+    }
+    operations();
+    for i in 0..100 {
+        eager.push(Counted(i));
+    }
+    assert_eq!(operations(), 100);
+    assert_eq!(eager.query(), Counted(4950));
+    assert_eq!(operations(), 0);
+    eager.pop();
+    assert_eq!(operations(), 99);
+    eager.push_all(&[Counted(100), Counted(101)]);
+    assert_eq!(operations(), 2);
+    assert_eq!(eager.query(), Counted(5151));
+    assert_eq!(operations(), 0);
+}
+
+#[test]
+fn query_excluding_newest() {
+    let mut window = ReCalc::<i64, Sum>::new();
+    assert_eq!(window.query_excluding_newest(), 0);
+    for i in 1..100 {
+        let before = window.query();
+        window.push(i);
+        assert_eq!(window.query_excluding_newest(), before);
+        if i % 3 == 0 {
+            window.pop();
+        }
+    }
+}
+
+#[test]
+fn query_absorbing() {
+    let mut window = ReCalc::<CountedAny, Max>::builder().absorbing().build();
+    let mut plain = ReCalc::<CountedAny, Max>::new();
+    assert_eq!(window.query(), CountedAny(Any(false)));
+    window.push(CountedAny(Any(true)));
+    plain.push(CountedAny(Any(true)));
+    for _ in 0..99 {
+        window.push(CountedAny(Any(false)));
+        plain.push(CountedAny(Any(false)));
+    }
+    operations();
+    assert_eq!(plain.query(), CountedAny(Any(true)));
+    assert_eq!(operations(), 100);
+    assert_eq!(window.query(), CountedAny(Any(true)));
+    assert_eq!(operations(), 1);
+    window.pop();
+    assert_eq!(window.query(), CountedAny(Any(false)));
+    assert_eq!(operations(), 99);
+    window.push(CountedAny(Any(true)));
+    assert_eq!(window.query(), CountedAny(Any(true)));
+    assert_eq!(operations(), 100);
+    assert_eq!(window.query(), CountedAny(Any(true)));
+    assert_eq!(operations(), 0);
+}
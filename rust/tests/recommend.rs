@@ -0,0 +1,11 @@
+use swag::recommend::*;
+
+#[test]
+fn recommend_extremes() {
+    assert_eq!(recommend(2, 0.0), AlgorithmKind::ReCalc);
+    assert_eq!(recommend(2, 1.0), AlgorithmKind::ReCalc);
+    assert_eq!(recommend(1_000_000, 1.0), AlgorithmKind::Reactive);
+    assert_eq!(recommend(1_000_000, 0.0), AlgorithmKind::TwoStacks);
+    assert_eq!(recommend(1000, 1.0), AlgorithmKind::TwoStacks);
+    assert_eq!(recommend(0, 1.0), AlgorithmKind::Adaptive);
+}
@@ -0,0 +1,49 @@
+use rand::Rng;
+use swag::ops::Concat;
+use swag::ops::LastN;
+use swag::retract::Retractable;
+use swag::*;
+
+/// Inserts five values, retracts the middle two, and checks the remaining three.
+#[test]
+fn retract_middle() {
+    let mut window = Retractable::<LastN<i64, 8>, Concat>::new();
+    let handles = (1..=5)
+        .map(|v| window.insert(LastN::from(v)))
+        .collect::<Vec<_>>();
+    assert_eq!(window.remove(handles[2]), Some(LastN::from(3)));
+    assert_eq!(window.remove(handles[1]), Some(LastN::from(2)));
+    assert_eq!(window.remove(handles[1]), None);
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.query(), LastN(vec![1, 4, 5]));
+    assert_eq!(window.pop(), Some(LastN::from(1)));
+    assert_eq!(window.pop(), Some(LastN::from(4)));
+    assert_eq!(window.remove(handles[0]), None);
+    assert_eq!(window.query(), LastN(vec![5]));
+}
+
+/// Retracts random values, interleaved with pushes and pops, and compares the
+/// aggregate against the remaining values.
+#[test]
+fn retract_random() {
+    let mut rng = rand::thread_rng();
+    let mut window = Retractable::<LastN<i64, 8>, Concat>::new();
+    let mut reference = Vec::new();
+    for i in 0..1000 {
+        reference.push((window.insert(LastN::from(i)), i));
+        if rng.gen_bool(0.3) && !reference.is_empty() {
+            let (handle, v) = reference.remove(rng.gen_range(0, reference.len()));
+            assert_eq!(window.remove(handle), Some(LastN::from(v)));
+        }
+        if rng.gen_bool(0.3) && !reference.is_empty() {
+            let (_, v) = reference.remove(0);
+            assert_eq!(window.pop(), Some(LastN::from(v)));
+        }
+        let expected = reference.iter().map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(window.len(), expected.len());
+        assert_eq!(
+            window.query(),
+            LastN(expected[expected.len().saturating_sub(8)..].to_vec())
+        );
+    }
+}
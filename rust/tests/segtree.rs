@@ -0,0 +1,78 @@
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::Operator;
+use rand::Rng;
+use swag::ops::Pair;
+use swag::segtree::SegTree;
+use swag::*;
+
+/// Issues random range queries over a window of pairs under `(Max, Min)`,
+/// interleaved with pushes and pops, and compares them against folds.
+#[test]
+fn random_range_queries() {
+    type Op = Pair<ops::Max, ops::Min>;
+    let mut rng = rand::thread_rng();
+    let mut window = SegTree::<(i64, i64), Op>::new();
+    let mut reference = std::collections::VecDeque::new();
+    for _ in 0..2000 {
+        let v = rng.gen_range(-1000, 1000);
+        window.push((v, v));
+        reference.push_back(v);
+        while rng.gen_bool(0.4) && !reference.is_empty() {
+            assert_eq!(window.pop().map(|(v, _)| v), reference.pop_front());
+        }
+        for _ in 0..10 {
+            let start = rng.gen_range(0, reference.len() + 1);
+            let end = rng.gen_range(start, reference.len() + 1);
+            let range = reference.range(start..end);
+            let expected = (
+                range.clone().copied().max().unwrap_or(i64::MIN),
+                range.copied().min().unwrap_or(i64::MAX),
+            );
+            assert_eq!(window.range_query(start..end), expected);
+        }
+        assert_eq!(window.len(), reference.len());
+    }
+}
+
+/// Checks that prefix and suffix queries of a window of strings are in fifo order.
+#[test]
+fn ordered_range_queries() {
+    let mut window = SegTree::<String, Concat>::new();
+    for c in "abcdefghij".chars() {
+        window.push(c.to_string());
+    }
+    window.pop();
+    window.pop();
+    assert_eq!(window.query(), "cdefghij");
+    assert_eq!(window.range_query(0..3), "cde");
+    assert_eq!(window.range_query(5..8), "hij");
+    assert_eq!(window.range_query(2..2), "");
+}
+
+/// Binary operator for concatenating strings, which is not commutative.
+#[derive(Copy, Clone)]
+struct Concat;
+
+impl Operator for Concat {
+    fn operator_token() -> Concat {
+        Concat
+    }
+}
+
+impl Identity<Concat> for String {
+    fn identity() -> String {
+        String::new()
+    }
+}
+
+impl AbstractMagma<Concat> for String {
+    fn operate(&self, other: &Self) -> Self {
+        format!("{}{}", self, other)
+    }
+}
+
+impl AbstractSemigroup<Concat> for String {}
+impl AbstractMonoid<Concat> for String {}
@@ -0,0 +1,151 @@
+use alga::general::AbstractGroup;
+use alga::general::AbstractLoop;
+use alga::general::AbstractMagma;
+use alga::general::AbstractMonoid;
+use alga::general::AbstractQuasigroup;
+use alga::general::AbstractSemigroup;
+use alga::general::Identity;
+use alga::general::TwoSidedInverse;
+use rand::Rng;
+use std::collections::VecDeque;
+use swag::ops::Complex;
+use swag::ops::Concat;
+use swag::ops::Sum;
+use swag::soe::SoE;
+use swag::*;
+
+/// Compacts a window after a long workload and checks that it shrinks without
+/// changing its aggregate.
+#[test]
+fn compact() {
+    let mut window = SoE::<Complex, Sum>::new();
+    for i in 0..10_000 {
+        window.push(Complex::new(i as f64, -i as f64));
+    }
+    while window.len() > 10 {
+        window.pop();
+    }
+    let agg = window.query();
+    let capacity = window.capacity();
+    window.compact();
+    assert_eq!(window.query(), agg);
+    assert_eq!(window.query(), Complex::new(99_945.0, -99_945.0));
+    assert!(window.capacity() < capacity);
+}
+
+/// Compacting recovers the exact aggregate after inexact subtractions.
+#[test]
+fn compact_drift() {
+    let mut window = SoE::<Complex, Sum>::new();
+    window.push(Complex::new(1e17, 0.0));
+    window.push(Complex::new(1.0, 0.0));
+    window.pop();
+    assert_ne!(window.query(), Complex::new(1.0, 0.0));
+    window.compact();
+    assert_eq!(window.query(), Complex::new(1.0, 0.0));
+}
+
+#[test]
+fn query_excluding_newest() {
+    let mut window = SoE::<Complex, Sum>::new();
+    assert_eq!(window.query_excluding_newest(), Complex::new(0.0, 0.0));
+    for i in 1..100 {
+        let before = window.query();
+        window.push(Complex::new(i as f64, 1.0));
+        assert_eq!(window.query_excluding_newest(), before);
+        if i % 3 == 0 {
+            window.pop();
+        }
+    }
+}
+
+/// A permutation of three elements, which forms a non-commutative group under
+/// composition.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct Perm([usize; 3]);
+
+impl Identity<Concat> for Perm {
+    fn identity() -> Perm {
+        Perm([0, 1, 2])
+    }
+}
+
+impl AbstractMagma<Concat> for Perm {
+    fn operate(&self, other: &Self) -> Self {
+        Perm([other.0[self.0[0]], other.0[self.0[1]], other.0[self.0[2]]])
+    }
+}
+
+impl TwoSidedInverse<Concat> for Perm {
+    fn two_sided_inverse(&self) -> Perm {
+        let mut inverse = [0; 3];
+        for (i, j) in self.0.iter().enumerate() {
+            inverse[*j] = i;
+        }
+        Perm(inverse)
+    }
+}
+
+impl AbstractSemigroup<Concat> for Perm {}
+impl AbstractMonoid<Concat> for Perm {}
+impl AbstractQuasigroup<Concat> for Perm {}
+impl AbstractLoop<Concat> for Perm {}
+impl AbstractGroup<Concat> for Perm {}
+
+/// Evicted values are removed from the front of the aggregate, so that the window
+/// also supports non-commutative groups.
+#[test]
+fn non_commutative() {
+    let perms = [[0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1]];
+    let mut rng = rand::thread_rng();
+    let mut window = SoE::<Perm, Concat>::new();
+    let mut reference = VecDeque::new();
+    for _ in 0..1000 {
+        let v = Perm(perms[rng.gen_range(0, perms.len())]);
+        window.push(v);
+        reference.push_back(v);
+        while rng.gen_bool(0.5) {
+            window.pop();
+            reference.pop_front();
+        }
+        let expected = reference
+            .iter()
+            .fold(Perm::identity(), |acc, v| acc.operate(v));
+        assert_eq!(window.query(), expected);
+    }
+}
+
+/// Compares subtract-on-evict against `Window` on a random sum workload.
+fn compare_with<Window>()
+where
+    Window: FifoWindow<i64, Sum>,
+{
+    let mut rng = rand::thread_rng();
+    let mut soe = SoE::<i64, Sum>::new();
+    let mut window = Window::new();
+    let mut reference = VecDeque::new();
+    for _ in 0..10_000 {
+        let v = rng.gen_range(1, 5);
+        soe.push(v);
+        window.push(v);
+        reference.push_back(v);
+        while rng.gen_bool(0.5) {
+            // FlatFIT evicts partial aggregates rather than the pushed values, so
+            // evictions are checked against the reference instead.
+            assert_eq!(soe.pop(), reference.pop_front());
+            window.pop();
+        }
+        assert_eq!(soe.query(), window.query());
+    }
+}
+
+/// Compares subtract-on-evict against every other algorithm.
+#[test]
+fn compare() {
+    compare_with::<recalc::ReCalc<i64, Sum>>();
+    compare_with::<reactive::Reactive<i64, Sum>>();
+    compare_with::<two_stacks::TwoStacks<i64, Sum>>();
+    compare_with::<flatfit::FlatFIT<i64, Sum>>();
+    compare_with::<adaptive::Adaptive<i64, Sum>>();
+    compare_with::<segtree::SegTree<i64, Sum>>();
+}
@@ -0,0 +1,47 @@
+use std::thread;
+use swag::ops::Sum;
+use swag::recalc::ReCalc;
+use swag::spsc::SyncWindow;
+use swag::FifoWindow;
+
+#[test]
+fn consumer_observes_final_aggregate() {
+    let (mut writer, mut reader) = SyncWindow::new::<Sum>(ReCalc::<i64, Sum>::new());
+    assert_eq!(reader.load(), 0);
+    let producer = thread::spawn(move || {
+        for i in 1..=10_000 {
+            writer.push::<Sum>(i);
+            if writer.len::<Sum>() > 100 {
+                writer.pop::<Sum>();
+            }
+        }
+        writer.query::<Sum>()
+    });
+    let consumer = thread::spawn(move || {
+        let expected = (9_901..=10_000).sum::<i64>();
+        let mut last = 0;
+        while last != expected {
+            last = reader.load();
+            thread::yield_now();
+        }
+        last
+    });
+    let final_agg = producer.join().unwrap();
+    assert_eq!(consumer.join().unwrap(), final_agg);
+}
+
+#[test]
+fn publish_every() {
+    let (writer, mut reader) = SyncWindow::new::<Sum>(ReCalc::<i64, Sum>::new());
+    let mut writer = writer.publish_every(3);
+    writer.push::<Sum>(1);
+    writer.push::<Sum>(2);
+    assert_eq!(reader.load(), 0);
+    writer.push::<Sum>(3);
+    assert_eq!(reader.load(), 6);
+    writer.pop::<Sum>();
+    assert_eq!(reader.load(), 6);
+    writer.publish::<Sum>();
+    assert_eq!(reader.load(), 5);
+    assert_eq!(reader.load(), 5);
+}
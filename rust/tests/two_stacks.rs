@@ -0,0 +1,119 @@
+#![cfg_attr(feature = "simd", feature(cfg_overflow_checks))]
+
+use rand::Rng;
+use swag::ops::Concat;
+use swag::ops::LastN;
+use swag::*;
+
+/// Flips stacks of every size up to a few vector widths, and compares the
+/// vectorized aggregates against a scalar recalculation.
+#[cfg(feature = "simd")]
+fn flips<Value>(gen: impl Fn(&mut rand::rngs::ThreadRng) -> Value)
+where
+    Value: Monoid<ops::Sum> + PartialEq + std::fmt::Debug,
+{
+    let mut rng = rand::thread_rng();
+    for n in 0..40 {
+        let mut window = two_stacks::TwoStacks::<Value, ops::Sum>::new();
+        let mut reference = recalc::ReCalc::<Value, ops::Sum>::new();
+        for _ in 0..n {
+            let v = gen(&mut rng);
+            window.push(v.clone());
+            reference.push(v);
+        }
+        while !window.is_empty() {
+            assert_eq!(window.pop(), reference.pop());
+            assert_eq!(window.query(), reference.query());
+            if rng.gen_bool(0.3) {
+                let v = gen(&mut rng);
+                window.push(v.clone());
+                reference.push(v);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_i32() {
+    flips(|rng| rng.gen_range(-1000_i32, 1000));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_i64() {
+    flips(|rng| rng.gen_range(-1000_i64, 1000));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_u32() {
+    flips(|rng| rng.gen_range(0_u32, 1000));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_u64() {
+    flips(|rng| rng.gen_range(0_u64, 1000));
+}
+
+/// A suffix sum of the front stack overflows, while every prefix sum of the back stack
+/// fits, so the overflow happens in the vectorized flip.
+#[cfg(feature = "simd")]
+#[cfg(overflow_checks)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn simd_overflow() {
+    let mut window = two_stacks::TwoStacks::<i64, ops::Sum>::new();
+    for v in [-1, -1, -1, -1, i64::MAX, 1].iter() {
+        window.push(*v);
+    }
+    window.pop();
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn scalar_i16() {
+    flips(|rng| rng.gen_range(-10_i16, 10));
+}
+
+/// Compares the aggregate excluding the newest value against the query before it was
+/// pushed, both when the newest value is on the back stack and on the front stack.
+#[test]
+fn query_excluding_newest() {
+    let mut rng = rand::thread_rng();
+    let mut window = two_stacks::TwoStacks::<LastN<i64, 4>, Concat>::new();
+    assert_eq!(window.query_excluding_newest(), LastN(vec![]));
+    for i in 0..1000 {
+        let before = window.query();
+        window.push(LastN::from(i));
+        assert_eq!(window.query_excluding_newest(), before);
+        while rng.gen_bool(0.5) && window.pop().is_some() {}
+    }
+}
+
+/// Checks that the partials are suffix aggregates of the front stack followed by
+/// prefix aggregates of the back stack.
+#[cfg(feature = "inspect")]
+#[test]
+fn partials() {
+    let mut window = two_stacks::TwoStacks::<LastN<i64, 8>, Concat>::new();
+    assert_eq!(window.partials(), vec![]);
+    for i in 0..5 {
+        window.push(LastN::from(i));
+    }
+    window.pop();
+    window.push(LastN::from(5));
+    window.push(LastN::from(6));
+    assert_eq!(
+        window.partials(),
+        vec![
+            LastN(vec![1, 2, 3, 4]),
+            LastN(vec![2, 3, 4]),
+            LastN(vec![3, 4]),
+            LastN(vec![4]),
+            LastN(vec![5]),
+            LastN(vec![5, 6]),
+        ]
+    );
+}